@@ -3,35 +3,59 @@ pub enum NodeType {
     ProgramType,
     VariableDeclarationType,
     AssignmentExpressionType,
+    ConditionalExpressionType,
+    PipelineExpressionType,
+    RangeExpressionType,
     MemberExpressionType,
     CallExpressionType,
+    BlockStatementType,
     ConditionalStatementType,
     WhileStatementType,
     LoopStatementType,
     ForEachStatementType,
     ForStatementType,
     FunctionDeclarationType,
+    FunctionExpressionType,
     ReturnStatementType,
     BreakStatementType,
-    ImportStatementType,
+    ContinueStatementType,
+    LabeledStatementType,
+    TryStatementType,
+    ThrowStatementType,
+    TypeAliasDeclarationType,
+    ImportDeclarationType,
+    ExportDeclarationType,
     ClassDeclarationType,
+    EnumDeclarationType,
     CommentType,
     PropertyType,
     ObjectLiteralType,
     ArrayLiteralType,
+    SpreadElementType,
     NumericLiteralType,
     IdentifierType,
+    PathExpressionType,
+    ThisExpressionType,
+    SuperExpressionType,
     BinaryExpressionType,
     UnaryExpressionType,
+    UpdateExpressionType,
+    YieldExpressionType,
     LogicalExpressionType,
     StringLiteralType,
     NullLiteralType,
     ExpressionStatementType,
+    EmptyStatementType,
+    MatchExpressionType,
 }
 
 pub trait Statement {
     fn kind(&self) -> NodeType;
     fn to_string(&self) -> String;
+
+    fn take_alternate(&mut self) -> Option<Box<dyn Statement>> {
+        return None;
+    }
 }
 
 pub struct Program {
@@ -56,7 +80,8 @@ impl Program {
 
 pub struct VariableDeclaration {
     pub constant: bool,
-    pub identifier: String,
+    pub pattern: Pattern,
+    pub type_annotation: Option<TypeExpr>,
     pub value: Box<dyn Expression>,
 }
 
@@ -68,29 +93,38 @@ impl Statement for VariableDeclaration {
     fn to_string(&self) -> String {
         let constant_str = if self.constant { "const " } else { "let " };
         return format!(
-            "{}{} = {};\n",
+            "{}{}{} = {};\n",
             constant_str,
-            self.identifier,
+            self.pattern.to_string(),
+            type_annotation_to_string(&self.type_annotation),
             self.value.to_string()
         );
     }
 }
 
 impl VariableDeclaration {
-    pub fn new(constant: bool, identifier: String, value: Box<dyn Expression>) -> Self {
+    pub fn new(
+        constant: bool,
+        pattern: Pattern,
+        type_annotation: Option<TypeExpr>,
+        value: Box<dyn Expression>,
+    ) -> Self {
         return VariableDeclaration {
             constant,
-            identifier,
+            pattern,
+            type_annotation,
             value,
         };
     }
 }
 
 pub struct FunctionDeclaration {
-    parameters: Vec<String>,
+    parameters: Vec<Parameter>,
+    return_type: Option<TypeExpr>,
     name: String,
     body: Vec<Box<dyn Statement>>,
     is_anonymous: bool,
+    is_generator: bool,
 }
 
 
@@ -100,21 +134,27 @@ impl Statement for FunctionDeclaration {
     }
 
     fn to_string(&self) -> String {
+        let keyword = if self.is_generator { "function*" } else { "function" };
         let mut prefix: String = "".into();
         if self.is_anonymous {
-            prefix = "function(".into();
+            prefix = format!("{}(", keyword);
         } else {
-            prefix = format!("function {}", self.name);
+            prefix = format!("{} {}", keyword, self.name);
         }
 
-        let params_str = self.parameters.join(", ");
+        let params_str = self.parameters
+            .iter()
+            .map(|param| param.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
         let mut body_str: String = String::new();
 
         for stmt in &self.body {
             body_str += &stmt.to_string();
         }
 
-        let function = format!("{}({}) {{\n\t{}\n}}\n", prefix, params_str, body_str);
+        let return_type_str = type_annotation_to_string(&self.return_type);
+        let function = format!("{}({}){} {{\n\t{}\n}}\n", prefix, params_str, return_type_str, body_str);
         println!("{}", function);
         return function;
     }
@@ -122,16 +162,98 @@ impl Statement for FunctionDeclaration {
 
 impl FunctionDeclaration {
     pub fn new(
-        parameters: Vec<String>,
+        parameters: Vec<Parameter>,
+        return_type: Option<TypeExpr>,
         name: String,
         body: Vec<Box<dyn Statement>>,
         is_anonymous: bool,
+        is_generator: bool,
     ) -> Self {
         return FunctionDeclaration {
             parameters,
+            return_type,
             name,
             body,
             is_anonymous,
+            is_generator,
+        };
+    }
+}
+
+pub struct FunctionExpression {
+    parameters: Vec<Parameter>,
+    return_type: Option<TypeExpr>,
+    name: Option<String>,
+    body: Vec<Box<dyn Statement>>,
+    is_arrow: bool,
+    is_generator: bool,
+}
+
+impl Statement for FunctionExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::FunctionExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        if self.is_arrow {
+            return format!("{} => {}", self.params_to_string(), self.body_to_string());
+        }
+
+        let keyword = if self.is_generator { "function*" } else { "function" };
+        match &self.name {
+            Some(name) => return format!("{} {}{}", keyword, name, self.signature_to_string()),
+            None => return format!("{}{}", keyword, self.signature_to_string()),
+        }
+    }
+}
+
+impl Expression for FunctionExpression {}
+
+impl FunctionExpression {
+    fn params_to_string(&self) -> String {
+        let params_str = self.parameters
+            .iter()
+            .map(|param| param.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        return format!("({})", params_str);
+    }
+
+    fn body_to_string(&self) -> String {
+        let mut body_str: String = String::new();
+
+        for stmt in &self.body {
+            body_str += &stmt.to_string();
+        }
+
+        return format!("{{\n{}}}", body_str);
+    }
+
+    pub fn signature_to_string(&self) -> String {
+        return format!(
+            "{}{} {}",
+            self.params_to_string(),
+            type_annotation_to_string(&self.return_type),
+            self.body_to_string()
+        );
+    }
+
+    pub fn new(
+        parameters: Vec<Parameter>,
+        return_type: Option<TypeExpr>,
+        name: Option<String>,
+        body: Vec<Box<dyn Statement>>,
+        is_arrow: bool,
+        is_generator: bool,
+    ) -> Self {
+        return FunctionExpression {
+            parameters,
+            return_type,
+            name,
+            body,
+            is_arrow,
+            is_generator,
         };
     }
 }
@@ -146,7 +268,7 @@ impl Statement for ReturnStatement {
     }
 
     fn to_string(&self) -> String {
-        return format!("return {}", self.value.to_string());
+        return format!("return {};\n", self.value.to_string());
     }
 }
 
@@ -156,7 +278,9 @@ impl ReturnStatement {
     }
 }
 
-pub struct BreakStatement;
+pub struct BreakStatement {
+    label: Option<String>,
+}
 
 impl Statement for BreakStatement {
     fn kind(&self) -> NodeType {
@@ -164,39 +288,341 @@ impl Statement for BreakStatement {
     }
 
     fn to_string(&self) -> String {
-        return String::from("break\n");
+        match &self.label {
+            Some(label) => return format!("break '{};\n", label),
+            None => return String::from("break;\n"),
+        }
     }
 }
 
 impl BreakStatement {
-    pub fn new() -> Self {
-        return BreakStatement;
+    pub fn new(label: Option<String>) -> Self {
+        return BreakStatement { label };
     }
 }
 
-pub struct ImportStatement {
-    path: String,
+pub struct ContinueStatement {
+    label: Option<String>,
 }
 
-impl Statement for ImportStatement {
+impl Statement for ContinueStatement {
     fn kind(&self) -> NodeType {
-        return NodeType::ImportStatementType;
+        return NodeType::ContinueStatementType;
     }
 
     fn to_string(&self) -> String {
-        return format!("import {}\n", self.path);
+        match &self.label {
+            Some(label) => return format!("continue '{};\n", label),
+            None => return String::from("continue;\n"),
+        }
     }
 }
 
-impl ImportStatement {
-    pub fn new(path: String) -> Self {
-        return ImportStatement { path };
+impl ContinueStatement {
+    pub fn new(label: Option<String>) -> Self {
+        return ContinueStatement { label };
+    }
+}
+
+pub struct LabeledStatement {
+    label: String,
+    body: Box<dyn Statement>,
+}
+
+impl Statement for LabeledStatement {
+    fn kind(&self) -> NodeType {
+        return NodeType::LabeledStatementType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!("'{}: {}", self.label, self.body.to_string());
+    }
+}
+
+impl LabeledStatement {
+    pub fn new(label: String, body: Box<dyn Statement>) -> Self {
+        return LabeledStatement { label, body };
+    }
+}
+
+pub enum TypeExpr {
+    Named(String),
+    Array(Box<TypeExpr>),
+    Object(Vec<(String, TypeExpr)>),
+    Union(Vec<TypeExpr>),
+    Function(Vec<TypeExpr>, Box<TypeExpr>),
+    Optional(Box<TypeExpr>),
+}
+
+impl TypeExpr {
+    pub fn to_string(&self) -> String {
+        match self {
+            TypeExpr::Named(name) => return name.clone(),
+            TypeExpr::Array(element) => {
+                if let TypeExpr::Union(_) | TypeExpr::Function(_, _) = **element {
+                    return format!("({})[]", element.to_string());
+                }
+                return format!("{}[]", element.to_string());
+            },
+            TypeExpr::Object(fields) => {
+                let fields_str = fields
+                    .iter()
+                    .map(|(name, field_type)| format!("{}: {}", name, field_type.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                return format!("{{{}}}", fields_str);
+            },
+            TypeExpr::Union(types) => {
+                return types
+                    .iter()
+                    .map(|union_type| union_type.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" | ");
+            },
+            TypeExpr::Function(params, return_type) => {
+                let params_str = params
+                    .iter()
+                    .map(|param| param.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                return format!("({}) => {}", params_str, return_type.to_string());
+            },
+            TypeExpr::Optional(inner) => {
+                if let TypeExpr::Union(_) | TypeExpr::Function(_, _) = **inner {
+                    return format!("({})?", inner.to_string());
+                }
+                return format!("{}?", inner.to_string());
+            },
+        }
+    }
+}
+
+fn type_annotation_to_string(type_annotation: &Option<TypeExpr>) -> String {
+    match type_annotation {
+        Some(type_expr) => return format!(": {}", type_expr.to_string()),
+        None => return String::new(),
+    }
+}
+
+pub struct TypeAliasDeclaration {
+    name: String,
+    type_expr: TypeExpr,
+}
+
+impl Statement for TypeAliasDeclaration {
+    fn kind(&self) -> NodeType {
+        return NodeType::TypeAliasDeclarationType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!("type {} = {};\n", self.name, self.type_expr.to_string());
+    }
+}
+
+impl TypeAliasDeclaration {
+    pub fn new(name: String, type_expr: TypeExpr) -> Self {
+        return TypeAliasDeclaration { name, type_expr };
+    }
+}
+
+pub struct CatchClause {
+    param: Option<Pattern>,
+    body: BlockStatement,
+}
+
+impl CatchClause {
+    pub fn new(param: Option<Pattern>, body: BlockStatement) -> Self {
+        return CatchClause { param, body };
+    }
+}
+
+pub struct TryStatement {
+    block: BlockStatement,
+    handler: Option<CatchClause>,
+    finalizer: Option<BlockStatement>,
+}
+
+impl Statement for TryStatement {
+    fn kind(&self) -> NodeType {
+        return NodeType::TryStatementType;
+    }
+
+    fn to_string(&self) -> String {
+        let mut result = format!("try {}", self.block.block_to_string());
+
+        if let Some(handler) = &self.handler {
+            match &handler.param {
+                Some(param) => result += &format!(" catch ({}) {}", param.to_string(), handler.body.block_to_string()),
+                None => result += &format!(" catch {}", handler.body.block_to_string()),
+            }
+        }
+
+        if let Some(finalizer) = &self.finalizer {
+            result += &format!(" finally {}", finalizer.block_to_string());
+        }
+
+        return format!("{}\n", result);
+    }
+}
+
+impl TryStatement {
+    pub fn new(
+        block: BlockStatement,
+        handler: Option<CatchClause>,
+        finalizer: Option<BlockStatement>,
+    ) -> Self {
+        return TryStatement {
+            block,
+            handler,
+            finalizer,
+        };
+    }
+}
+
+pub struct ThrowStatement {
+    argument: Box<dyn Expression>,
+}
+
+impl Statement for ThrowStatement {
+    fn kind(&self) -> NodeType {
+        return NodeType::ThrowStatementType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!("throw {};\n", self.argument.to_string());
+    }
+}
+
+impl ThrowStatement {
+    pub fn new(argument: Box<dyn Expression>) -> Self {
+        return ThrowStatement { argument };
+    }
+}
+
+pub enum ImportSpecifier {
+    Named { imported: String, local: String },
+    Namespace { local: String },
+}
+
+impl ImportSpecifier {
+    pub fn local(&self) -> &str {
+        match self {
+            ImportSpecifier::Named { local, .. } => return local,
+            ImportSpecifier::Namespace { local } => return local,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            ImportSpecifier::Named { imported, local } => {
+                if imported == local {
+                    return imported.clone();
+                }
+                return format!("{} as {}", imported, local);
+            },
+            ImportSpecifier::Namespace { local } => return format!("* as {}", local),
+        }
+    }
+}
+
+pub struct ImportDeclaration {
+    pub specifiers: Vec<ImportSpecifier>,
+    pub source: String,
+}
+
+impl Statement for ImportDeclaration {
+    fn kind(&self) -> NodeType {
+        return NodeType::ImportDeclarationType;
+    }
+
+    fn to_string(&self) -> String {
+        if self.specifiers.is_empty() {
+            return format!("import \"{}\";\n", self.source);
+        }
+
+        if let [ImportSpecifier::Namespace { .. }] = self.specifiers.as_slice() {
+            return format!("import {} from \"{}\";\n", self.specifiers[0].to_string(), self.source);
+        }
+
+        let specifiers_str = self.specifiers
+            .iter()
+            .map(|specifier| specifier.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        return format!("import {{ {} }} from \"{}\";\n", specifiers_str, self.source);
+    }
+}
+
+impl ImportDeclaration {
+    pub fn new(specifiers: Vec<ImportSpecifier>, source: String) -> Self {
+        return ImportDeclaration { specifiers, source };
+    }
+}
+
+pub struct ExportDeclaration {
+    pub declaration: Box<dyn Statement>,
+}
+
+impl Statement for ExportDeclaration {
+    fn kind(&self) -> NodeType {
+        return NodeType::ExportDeclarationType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!("export {}", self.declaration.to_string());
+    }
+}
+
+impl ExportDeclaration {
+    pub fn new(declaration: Box<dyn Statement>) -> Self {
+        return ExportDeclaration { declaration };
+    }
+}
+
+pub enum ClassMember {
+    Field {
+        name: String,
+        type_annotation: Option<TypeExpr>,
+        value: Option<Box<dyn Expression>>,
+        is_static: bool,
+    },
+    Method {
+        name: String,
+        function: FunctionExpression,
+        is_static: bool,
+    },
+    Constructor(FunctionExpression),
+}
+
+impl ClassMember {
+    pub fn to_string(&self) -> String {
+        match self {
+            ClassMember::Field { name, type_annotation, value, is_static } => {
+                let static_str = if *is_static { "static " } else { "" };
+                let type_str = type_annotation_to_string(type_annotation);
+                match value {
+                    Some(value) => return format!("{}{}{} = {};\n", static_str, name, type_str, value.to_string()),
+                    None => return format!("{}{}{};\n", static_str, name, type_str),
+                }
+            },
+            ClassMember::Method { name, function, is_static } => {
+                let static_str = if *is_static { "static " } else { "" };
+                return format!("{}{}{}\n", static_str, name, function.signature_to_string());
+            },
+            ClassMember::Constructor(function) => {
+                return format!("constructor{}\n", function.signature_to_string());
+            },
+        }
     }
 }
 
 pub struct ClassDeclaration {
     name: String,
-    body: Vec<Box<dyn Statement>>,
+    superclass: Option<String>,
+    members: Vec<ClassMember>,
 }
 
 impl Statement for ClassDeclaration {
@@ -205,19 +631,76 @@ impl Statement for ClassDeclaration {
     }
 
     fn to_string(&self) -> String {
-        let mut body_str: String = String::new();
+        let mut members_str: String = String::new();
 
-        for stmt in &self.body {
-            body_str += &stmt.to_string();
+        for member in &self.members {
+            members_str += &member.to_string();
         }
 
-        return format!("class {} {{\n{}}}\n", self.name, body_str);
+        match &self.superclass {
+            Some(superclass) => return format!("class {} extends {} {{\n{}}}\n", self.name, superclass, members_str),
+            None => return format!("class {} {{\n{}}}\n", self.name, members_str),
+        }
     }
 }
 
 impl ClassDeclaration {
-    pub fn new(name: String, body: Vec<Box<dyn Statement>>) -> Self {
-        return ClassDeclaration { name, body };
+    pub fn new(name: String, superclass: Option<String>, members: Vec<ClassMember>) -> Self {
+        return ClassDeclaration {
+            name,
+            superclass,
+            members,
+        };
+    }
+}
+
+pub struct EnumVariant {
+    pub name: String,
+    pub value: Option<Box<dyn Expression>>,
+    pub fields: Option<Vec<String>>,
+}
+
+impl EnumVariant {
+    pub fn new(name: String, value: Option<Box<dyn Expression>>, fields: Option<Vec<String>>) -> Self {
+        return EnumVariant { name, value, fields };
+    }
+
+    pub fn to_string(&self) -> String {
+        if let Some(value) = &self.value {
+            return format!("{} = {}", self.name, value.to_string());
+        }
+
+        match &self.fields {
+            Some(fields) => return format!("{}({})", self.name, fields.join(", ")),
+            None => return self.name.clone(),
+        }
+    }
+}
+
+pub struct EnumDeclaration {
+    name: String,
+    variants: Vec<EnumVariant>,
+}
+
+impl Statement for EnumDeclaration {
+    fn kind(&self) -> NodeType {
+        return NodeType::EnumDeclarationType;
+    }
+
+    fn to_string(&self) -> String {
+        let mut variants_str: String = String::new();
+
+        for variant in &self.variants {
+            variants_str += &format!("{},\n", variant.to_string());
+        }
+
+        return format!("enum {} {{\n{}}}\n", self.name, variants_str);
+    }
+}
+
+impl EnumDeclaration {
+    pub fn new(name: String, variants: Vec<EnumVariant>) -> Self {
+        return EnumDeclaration { name, variants };
     }
 }
 
@@ -241,44 +724,75 @@ impl Statement for Comment {
     }
 }
 
-pub struct ConditionalStatement {
-    condition: Box<dyn Expression>,
-    body: Vec<Box<dyn Statement>>,
-    alternate: Vec<Box<dyn Statement>>,
+pub struct BlockStatement {
+    pub body: Vec<Box<dyn Statement>>,
 }
 
-impl Statement for ConditionalStatement {
+impl Statement for BlockStatement {
     fn kind(&self) -> NodeType {
-        return NodeType::ConditionalStatementType;
+        return NodeType::BlockStatementType;
     }
 
     fn to_string(&self) -> String {
+        return format!("{}\n", self.block_to_string());
+    }
+}
+
+impl BlockStatement {
+    pub fn block_to_string(&self) -> String {
         let mut body_str: String = String::new();
 
         for stmt in &self.body {
             body_str += &stmt.to_string();
         }
 
-        let mut alternate_str: String = String::new();
+        return format!("{{\n{}}}", body_str);
+    }
+
+    pub fn new(body: Vec<Box<dyn Statement>>) -> Self {
+        return BlockStatement { body };
+    }
+}
+
+pub struct ConditionalStatement {
+    condition: Box<dyn Expression>,
+    body: BlockStatement,
+    alternate: Option<Box<dyn Statement>>,
+}
+
+impl Statement for ConditionalStatement {
+    fn kind(&self) -> NodeType {
+        return NodeType::ConditionalStatementType;
+    }
+
+    fn to_string(&self) -> String {
+        let if_str = format!("if ({}) {}", self.condition.to_string(), self.body.block_to_string());
 
-        for stmt in &self.alternate {
-            alternate_str += &stmt.to_string();
+        match &self.alternate {
+            Some(alternate) => return format!("{} else {}", if_str, alternate.to_string()),
+            None => return format!("{}\n", if_str),
         }
+    }
 
-        return format!(
-            "if ({}) {{\n{}}} else {{\n{}}}\n",
-            self.condition.to_string(),
-            body_str,
-            alternate_str
-        );
+    fn take_alternate(&mut self) -> Option<Box<dyn Statement>> {
+        return self.alternate.take();
+    }
+}
+
+impl Drop for ConditionalStatement {
+    fn drop(&mut self) {
+        let mut alternate = self.alternate.take();
+        while let Some(mut statement) = alternate {
+            alternate = statement.take_alternate();
+        }
     }
 }
 
 impl ConditionalStatement {
     pub fn new(
         condition: Box<dyn Expression>,
-        body: Vec<Box<dyn Statement>>,
-        alternate: Vec<Box<dyn Statement>>,
+        body: BlockStatement,
+        alternate: Option<Box<dyn Statement>>,
     ) -> Self {
         return ConditionalStatement {
             condition,
@@ -290,7 +804,7 @@ impl ConditionalStatement {
 
 pub struct WhileStatement {
     condition: Box<dyn Expression>,
-    body: Vec<Box<dyn Statement>>,
+    body: BlockStatement,
 }
 
 impl Statement for WhileStatement {
@@ -299,28 +813,18 @@ impl Statement for WhileStatement {
     }
 
     fn to_string(&self) -> String {
-        let mut body_str: String = String::new();
-
-        for stmt in &self.body {
-            body_str += &stmt.to_string();
-        }
-
-        return format!(
-            "while ({}) {{\n{}}}\n",
-            self.condition.to_string(),
-            body_str
-        );
+        return format!("while ({}) {}\n", self.condition.to_string(), self.body.block_to_string());
     }
 }
 
 impl WhileStatement {
-    pub fn new(condition: Box<dyn Expression>, body: Vec<Box<dyn Statement>>) -> Self {
+    pub fn new(condition: Box<dyn Expression>, body: BlockStatement) -> Self {
         return WhileStatement { condition, body };
     }
 }
 
 pub struct LoopStatement {
-    body: Vec<Box<dyn Statement>>,
+    body: BlockStatement,
 }
 
 impl Statement for LoopStatement {
@@ -329,26 +833,20 @@ impl Statement for LoopStatement {
     }
 
     fn to_string(&self) -> String {
-        let mut body_str: String = String::new();
-
-        for stmt in &self.body {
-            body_str += &stmt.to_string();
-        }
-
-        return format!("loop {{\n{}}}\n", body_str);
+        return format!("loop {}\n", self.body.block_to_string());
     }
 }
 
 impl LoopStatement {
-    pub fn new(body: Vec<Box<dyn Statement>>) -> Self {
+    pub fn new(body: BlockStatement) -> Self {
         return LoopStatement { body };
     }
 }
 
 pub struct ForEachStatement {
-    variable: String,
+    variable: Pattern,
     collection: Box<dyn Expression>,
-    body: Vec<Box<dyn Statement>>,
+    body: BlockStatement,
 }
 
 impl Statement for ForEachStatement {
@@ -357,26 +855,20 @@ impl Statement for ForEachStatement {
     }
 
     fn to_string(&self) -> String {
-        let mut body_str: String = String::new();
-
-        for stmt in &self.body {
-            body_str += &stmt.to_string();
-        }
-
         return format!(
-            "for ({} in {}) {{\n{}}}\n",
-            self.variable,
+            "for ({} in {}) {}\n",
+            self.variable.to_string(),
             self.collection.to_string(),
-            body_str
+            self.body.block_to_string()
         );
     }
 }
 
 impl ForEachStatement {
     pub fn new(
-        variable: String,
+        variable: Pattern,
         collection: Box<dyn Expression>,
-        body: Vec<Box<dyn Statement>>,
+        body: BlockStatement,
     ) -> Self {
         return ForEachStatement {
             variable,
@@ -386,79 +878,386 @@ impl ForEachStatement {
     }
 }
 
-pub struct ForStatement {
-    init: Box<dyn Statement>,
-    condition: Box<dyn Expression>,
-    update: Box<dyn Expression>,
-    body: Vec<Box<dyn Statement>>,
+pub struct ForStatement {
+    init: Box<dyn Statement>,
+    condition: Box<dyn Expression>,
+    update: Box<dyn Expression>,
+    body: BlockStatement,
+}
+
+impl Statement for ForStatement {
+    fn kind(&self) -> NodeType {
+        return NodeType::ForStatementType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!(
+            "for ({} {}; {}) {}\n",
+            self.init.to_string().trim_end(),
+            self.condition.to_string(),
+            self.update.to_string(),
+            self.body.block_to_string()
+        );
+    }
+}
+
+impl ForStatement {
+    pub fn new(
+        init: Box<dyn Statement>,
+        condition: Box<dyn Expression>,
+        update: Box<dyn Expression>,
+        body: BlockStatement,
+    ) -> Self {
+        return ForStatement {
+            init,
+            condition,
+            update,
+            body,
+        };
+    }
+}
+
+
+
+pub trait Expression: Statement {
+    fn into_pattern(self: Box<Self>) -> Option<Pattern> {
+        return None;
+    }
+
+    fn is_optional_chain(&self) -> bool {
+        return false;
+    }
+
+    fn take_operands(&mut self, _operands: &mut Vec<Box<dyn Expression>>) {}
+}
+
+fn take_operand(operand: &mut Box<dyn Expression>) -> Box<dyn Expression> {
+    return std::mem::replace(operand, Box::new(NullLiteral::new()));
+}
+
+fn drop_operands(expression: &mut dyn Expression) {
+    let mut operands: Vec<Box<dyn Expression>> = Vec::new();
+    expression.take_operands(&mut operands);
+
+    while let Some(mut operand) = operands.pop() {
+        operand.take_operands(&mut operands);
+    }
+}
+
+pub struct PatternProperty {
+    pub key: String,
+    pub value: Pattern,
+    pub shorthand: bool,
+}
+
+pub enum Pattern {
+    Identifier(String),
+    Member(Box<dyn Expression>),
+    Array(Vec<Pattern>),
+    Object {
+        properties: Vec<PatternProperty>,
+        rest: Option<Box<Pattern>>,
+    },
+    Rest(Box<Pattern>),
+    Default(Box<Pattern>, Box<dyn Expression>),
+}
+
+impl Pattern {
+    pub fn to_string(&self) -> String {
+        match self {
+            Pattern::Identifier(name) => return name.clone(),
+            Pattern::Member(expression) => return expression.to_string(),
+            Pattern::Array(elements) => {
+                let elements_str = elements
+                    .iter()
+                    .map(|elem| elem.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                return format!("[{}]", elements_str);
+            },
+            Pattern::Object { properties, rest } => {
+                let mut properties_str: Vec<String> = properties
+                    .iter()
+                    .map(|prop| {
+                        if prop.shorthand {
+                            return prop.value.to_string();
+                        }
+                        return format!("{}: {}", prop.key, prop.value.to_string());
+                    })
+                    .collect();
+
+                if let Some(rest) = rest {
+                    properties_str.push(format!("...{}", rest.to_string()));
+                }
+
+                return format!("{{{}}}", properties_str.join(", "));
+            },
+            Pattern::Rest(target) => return format!("...{}", target.to_string()),
+            Pattern::Default(target, value) => return format!("{} = {}", target.to_string(), value.to_string()),
+        }
+    }
+
+    pub fn bound_names(&self) -> Vec<String> {
+        match self {
+            Pattern::Identifier(name) => return vec![name.clone()],
+            Pattern::Member(_) => return Vec::new(),
+            Pattern::Array(elements) => return elements.iter().flat_map(|elem| elem.bound_names()).collect(),
+            Pattern::Object { properties, rest } => {
+                let mut names: Vec<String> = properties.iter().flat_map(|prop| prop.value.bound_names()).collect();
+                if let Some(rest) = rest {
+                    names.extend(rest.bound_names());
+                }
+                return names;
+            },
+            Pattern::Rest(target) => return target.bound_names(),
+            Pattern::Default(target, _) => return target.bound_names(),
+        }
+    }
+}
+
+pub struct Parameter {
+    pub pattern: Pattern,
+    pub type_annotation: Option<TypeExpr>,
+    pub default: Option<Box<dyn Expression>>,
+    pub is_rest: bool,
+}
+
+impl Parameter {
+    pub fn new(
+        pattern: Pattern,
+        type_annotation: Option<TypeExpr>,
+        default: Option<Box<dyn Expression>>,
+        is_rest: bool,
+    ) -> Self {
+        return Parameter {
+            pattern,
+            type_annotation,
+            default,
+            is_rest,
+        };
+    }
+
+    pub fn to_string(&self) -> String {
+        let rest_str = if self.is_rest { "..." } else { "" };
+        let param_str = format!(
+            "{}{}{}",
+            rest_str,
+            self.pattern.to_string(),
+            type_annotation_to_string(&self.type_annotation)
+        );
+
+        match &self.default {
+            Some(default) => return format!("{} = {}", param_str, default.to_string()),
+            None => return param_str,
+        }
+    }
+}
+
+pub struct AssignmentExpression {
+    pub assignee: Pattern,
+    pub value: Box<dyn Expression>,
+    pub operator: String,
+}
+
+impl Statement for AssignmentExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::AssignmentExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!(
+            "{} {} {}",
+            self.assignee.to_string(),
+            self.operator,
+            self.value.to_string()
+        );
+    }
+}
+
+impl Expression for AssignmentExpression {
+    fn into_pattern(self: Box<Self>) -> Option<Pattern> {
+        if self.operator != "=" {
+            return None;
+        }
+        return Some(Pattern::Default(Box::new(self.assignee), self.value));
+    }
+}
+
+impl AssignmentExpression {
+    pub fn new(assignee: Pattern, value: Box<dyn Expression>, operator: String) -> Self {
+        return AssignmentExpression {
+            assignee,
+            value,
+            operator,
+        };
+    }
+}
+
+pub struct ConditionalExpression {
+    test: Box<dyn Expression>,
+    consequent: Box<dyn Expression>,
+    alternate: Box<dyn Expression>,
+}
+
+impl Statement for ConditionalExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::ConditionalExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!(
+            "{} ? {} : {}",
+            self.test.to_string(),
+            self.consequent.to_string(),
+            self.alternate.to_string()
+        );
+    }
+}
+
+impl Expression for ConditionalExpression {}
+
+impl ConditionalExpression {
+    pub fn new(
+        test: Box<dyn Expression>,
+        consequent: Box<dyn Expression>,
+        alternate: Box<dyn Expression>,
+    ) -> Self {
+        return ConditionalExpression {
+            test,
+            consequent,
+            alternate,
+        };
+    }
+}
+
+pub struct PipelineExpression {
+    argument: Box<dyn Expression>,
+    callee: Box<dyn Expression>,
+    arguments: Vec<Box<dyn Expression>>,
 }
 
-impl Statement for ForStatement {
+impl Statement for PipelineExpression {
     fn kind(&self) -> NodeType {
-        return NodeType::ForStatementType;
+        return NodeType::PipelineExpressionType;
     }
 
     fn to_string(&self) -> String {
-        let mut body_str: String = String::new();
+        let arguments_str = self.arguments
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
 
-        for stmt in &self.body {
-            body_str += &stmt.to_string();
-        }
+        return format!("{} |> {}({})", self.argument.to_string(), self.callee.to_string(), arguments_str);
+    }
+}
 
-        return format!(
-            "for ({}, {}; {}) {{\n{}}}\n",
-            self.init.to_string(),
-            self.condition.to_string(),
-            self.update.to_string(),
-            body_str
-        );
+impl Expression for PipelineExpression {
+    fn take_operands(&mut self, operands: &mut Vec<Box<dyn Expression>>) {
+        operands.push(take_operand(&mut self.argument));
+        operands.push(take_operand(&mut self.callee));
+        operands.append(&mut self.arguments);
     }
 }
 
-impl ForStatement {
-    pub fn new(
-        init: Box<dyn Statement>,
-        condition: Box<dyn Expression>,
-        update: Box<dyn Expression>,
-        body: Vec<Box<dyn Statement>>,
-    ) -> Self {
-        return ForStatement {
-            init,
-            condition,
-            update,
-            body,
+impl Drop for PipelineExpression {
+    fn drop(&mut self) {
+        drop_operands(self);
+    }
+}
+
+impl PipelineExpression {
+    pub fn new(argument: Box<dyn Expression>, callee: Box<dyn Expression>, arguments: Vec<Box<dyn Expression>>) -> Self {
+        return PipelineExpression {
+            argument,
+            callee,
+            arguments,
         };
     }
+
+    pub fn desugar(mut self) -> CallExpression {
+        let mut arguments = vec![take_operand(&mut self.argument)];
+        arguments.append(&mut self.arguments);
+        return CallExpression::new(take_operand(&mut self.callee), arguments, false);
+    }
 }
 
+pub struct YieldExpression {
+    argument: Option<Box<dyn Expression>>,
+    delegate: bool,
+}
 
+impl Statement for YieldExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::YieldExpressionType;
+    }
 
-pub trait Expression: Statement {}
+    fn to_string(&self) -> String {
+        let keyword = if self.delegate { "yield*" } else { "yield" };
+        match &self.argument {
+            Some(argument) => return format!("{} {}", keyword, argument.to_string()),
+            None => return keyword.to_string(),
+        }
+    }
+}
 
-pub struct AssignmentExpression {
-    pub assignee: Box<dyn Expression>,
-    pub value: Box<dyn Expression>,
+impl Expression for YieldExpression {}
+
+impl YieldExpression {
+    pub fn new(argument: Option<Box<dyn Expression>>, delegate: bool) -> Self {
+        return YieldExpression { argument, delegate };
+    }
 }
 
-impl Statement for AssignmentExpression {
+pub struct RangeExpression {
+    start: Option<Box<dyn Expression>>,
+    end: Option<Box<dyn Expression>>,
+    inclusive: bool,
+    step: Option<Box<dyn Expression>>,
+}
+
+impl Statement for RangeExpression {
     fn kind(&self) -> NodeType {
-        return NodeType::AssignmentExpressionType;
+        return NodeType::RangeExpressionType;
     }
 
     fn to_string(&self) -> String {
-        return format!(
-            "{} = {}",
-            self.assignee.to_string(),
-            self.value.to_string()
-        );
+        let mut result = String::new();
+
+        if let Some(start) = &self.start {
+            result += &start.to_string();
+        }
+
+        result += if self.inclusive { "..=" } else { ".." };
+
+        if let Some(end) = &self.end {
+            result += &end.to_string();
+        }
+
+        if let Some(step) = &self.step {
+            result += &format!(" step {}", step.to_string());
+        }
+
+        return result;
     }
 }
 
-impl Expression for AssignmentExpression {}
+impl Expression for RangeExpression {}
 
-impl AssignmentExpression {
-    pub fn new(assignee: Box<dyn Expression>, value: Box<dyn Expression>) -> Self {
-        return AssignmentExpression { assignee, value };
+impl RangeExpression {
+    pub fn new(
+        start: Option<Box<dyn Expression>>,
+        end: Option<Box<dyn Expression>>,
+        inclusive: bool,
+        step: Option<Box<dyn Expression>>,
+    ) -> Self {
+        return RangeExpression {
+            start,
+            end,
+            inclusive,
+            step,
+        };
     }
 }
 
@@ -483,7 +1282,18 @@ impl Statement for BinaryExpression {
     }
 }
 
-impl Expression for BinaryExpression {}
+impl Expression for BinaryExpression {
+    fn take_operands(&mut self, operands: &mut Vec<Box<dyn Expression>>) {
+        operands.push(take_operand(&mut self.left));
+        operands.push(take_operand(&mut self.right));
+    }
+}
+
+impl Drop for BinaryExpression {
+    fn drop(&mut self) {
+        drop_operands(self);
+    }
+}
 
 impl BinaryExpression {
     pub fn new(left: Box<dyn Expression>, right: Box<dyn Expression>, operator: String) -> Self {
@@ -518,6 +1328,37 @@ impl UnaryExpression {
     }
 }
 
+pub struct UpdateExpression {
+    prefix: bool,
+    operator: String,
+    argument: Box<dyn Expression>,
+}
+
+impl Statement for UpdateExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::UpdateExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        if self.prefix {
+            return format!("{}{}", self.operator, self.argument.to_string());
+        }
+        return format!("{}{}", self.argument.to_string(), self.operator);
+    }
+}
+
+impl Expression for UpdateExpression {}
+
+impl UpdateExpression {
+    pub fn new(prefix: bool, operator: String, argument: Box<dyn Expression>) -> Self {
+        return UpdateExpression {
+            prefix,
+            operator,
+            argument,
+        };
+    }
+}
+
 pub struct LogicalExpression {
     pub left: Box<dyn Expression>,
     pub right: Box<dyn Expression>,
@@ -531,7 +1372,7 @@ impl Statement for LogicalExpression {
 
     fn to_string(&self) -> String {
         if self.operator == "not" {
-            return format!("{}{}", self.operator, self.right.to_string());
+            return format!("{} {}", self.operator, self.right.to_string());
         } else {
             return format!(
                 "{} {} {}",
@@ -543,7 +1384,18 @@ impl Statement for LogicalExpression {
     }
 }
 
-impl Expression for LogicalExpression {}
+impl Expression for LogicalExpression {
+    fn take_operands(&mut self, operands: &mut Vec<Box<dyn Expression>>) {
+        operands.push(take_operand(&mut self.left));
+        operands.push(take_operand(&mut self.right));
+    }
+}
+
+impl Drop for LogicalExpression {
+    fn drop(&mut self) {
+        drop_operands(self);
+    }
+}
 
 impl LogicalExpression {
     pub fn new(left: Box<dyn Expression>, right: Box<dyn Expression>, operator: String) -> Self {
@@ -569,7 +1421,11 @@ impl Statement for Identifier {
     }
 }
 
-impl Expression for Identifier {}
+impl Expression for Identifier {
+    fn into_pattern(self: Box<Self>) -> Option<Pattern> {
+        return Some(Pattern::Identifier(self.sybmol));
+    }
+}
 
 impl Identifier {
     pub fn new(symbol: String) -> Self {
@@ -577,6 +1433,80 @@ impl Identifier {
     }
 }
 
+pub struct PathExpression {
+    pub segments: Vec<String>,
+}
+
+impl Statement for PathExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::PathExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        return self.segments.join("::");
+    }
+}
+
+impl Expression for PathExpression {}
+
+impl PathExpression {
+    pub fn new(segments: Vec<String>) -> Self {
+        return PathExpression { segments };
+    }
+}
+
+pub struct ThisExpression {}
+
+impl Statement for ThisExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::ThisExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        return String::from("this");
+    }
+}
+
+impl Expression for ThisExpression {}
+
+impl ThisExpression {
+    pub fn new() -> Self {
+        return ThisExpression {};
+    }
+}
+
+impl Default for ThisExpression {
+    fn default() -> Self {
+        return ThisExpression::new();
+    }
+}
+
+pub struct SuperExpression {}
+
+impl Statement for SuperExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::SuperExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        return String::from("super");
+    }
+}
+
+impl Expression for SuperExpression {}
+
+impl SuperExpression {
+    pub fn new() -> Self {
+        return SuperExpression {};
+    }
+}
+
+impl Default for SuperExpression {
+    fn default() -> Self {
+        return SuperExpression::new();
+    }
+}
+
 pub struct NumericLiteral {
     value: f64,
 }
@@ -641,9 +1571,41 @@ impl NullLiteral {
     }
 }
 
+impl Default for NullLiteral {
+    fn default() -> Self {
+        return NullLiteral::new();
+    }
+}
+
+pub enum PropertyKey {
+    Identifier(String),
+    String(String),
+    Numeric(f64),
+    Computed(Box<dyn Expression>),
+}
+
+impl PropertyKey {
+    pub fn to_string(&self) -> String {
+        match self {
+            PropertyKey::Identifier(name) => return name.clone(),
+            PropertyKey::String(value) => return format!("\"{}\"", value),
+            PropertyKey::Numeric(value) => return value.to_string(),
+            PropertyKey::Computed(expression) => return format!("[{}]", expression.to_string()),
+        }
+    }
+}
+
+pub enum PropertyKind {
+    Init,
+    Shorthand,
+    Method,
+    Spread,
+}
+
 pub struct Property {
-    key: String,
+    key: Option<PropertyKey>,
     value: Box<dyn Expression>,
+    kind: PropertyKind,
 }
 
 impl Statement for Property {
@@ -652,15 +1614,28 @@ impl Statement for Property {
     }
 
     fn to_string(&self) -> String {
-        return format!("{}: {}", self.key, self.value.to_string());
+        let key_str = match &self.key {
+            Some(key) => key.to_string(),
+            None => String::new(),
+        };
+
+        match self.kind {
+            PropertyKind::Init => return format!("{}: {}", key_str, self.value.to_string()),
+            PropertyKind::Shorthand => return key_str,
+            PropertyKind::Method => {
+                let function_str = self.value.to_string();
+                return format!("{}{}", key_str, function_str.strip_prefix("function").unwrap_or(&function_str));
+            },
+            PropertyKind::Spread => return format!("...{}", self.value.to_string()),
+        }
     }
 }
 
 impl Expression for Property {}
 
 impl Property {
-    pub fn new(key: String, value: Box<dyn Expression>) -> Self {
-        return Property { key, value };
+    pub fn new(key: Option<PropertyKey>, value: Box<dyn Expression>, kind: PropertyKind) -> Self {
+        return Property { key, value, kind };
     }
 }
 
@@ -674,17 +1649,49 @@ impl Statement for ObjectLiteral {
     }
 
     fn to_string(&self) -> String {
-        let mut properties_str: String = String::new();
-
-        for prop in &self.properties {
-            properties_str += &prop.to_string();
-        }
+        let properties_str = self.properties
+            .iter()
+            .map(|prop| prop.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
 
         return format!("{{{}}}", properties_str);
     }
 }
 
-impl Expression for ObjectLiteral {}
+impl Expression for ObjectLiteral {
+    fn into_pattern(self: Box<Self>) -> Option<Pattern> {
+        let count = self.properties.len();
+        let mut properties: Vec<PatternProperty> = Vec::new();
+        let mut rest: Option<Box<Pattern>> = None;
+
+        for (i, prop) in self.properties.into_iter().enumerate() {
+            match prop.kind {
+                PropertyKind::Spread => {
+                    if i != count - 1 {
+                        return None;
+                    }
+                    rest = Some(Box::new(prop.value.into_pattern()?));
+                },
+                PropertyKind::Init | PropertyKind::Shorthand => {
+                    let key = match prop.key {
+                        Some(PropertyKey::Identifier(name)) | Some(PropertyKey::String(name)) => name,
+                        _ => return None,
+                    };
+
+                    properties.push(PatternProperty {
+                        key,
+                        value: prop.value.into_pattern()?,
+                        shorthand: matches!(prop.kind, PropertyKind::Shorthand),
+                    });
+                },
+                PropertyKind::Method => return None,
+            }
+        }
+
+        return Some(Pattern::Object { properties, rest });
+    }
+}
 
 impl ObjectLiteral {
     pub fn new(properties: Vec<Property>) -> Self {
@@ -702,17 +1709,34 @@ impl Statement for ArrayLiteral {
     }
 
     fn to_string(&self) -> String {
-        let mut elements_str: String = String::new();
-
-        for elem in &self.elements {
-            elements_str += &elem.to_string();
-        }
+        let elements_str = self.elements
+            .iter()
+            .map(|elem| elem.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
 
         return format!("[{}]", elements_str);
     }
 }
 
-impl Expression for ArrayLiteral {}
+impl Expression for ArrayLiteral {
+    fn into_pattern(self: Box<Self>) -> Option<Pattern> {
+        let count = self.elements.len();
+        let mut elements: Vec<Pattern> = Vec::new();
+
+        for (i, elem) in self.elements.into_iter().enumerate() {
+            let pattern = elem.into_pattern()?;
+            if let Pattern::Rest(_) = pattern {
+                if i != count - 1 {
+                    return None;
+                }
+            }
+            elements.push(pattern);
+        }
+
+        return Some(Pattern::Array(elements));
+    }
+}
 
 impl ArrayLiteral {
     pub fn new(elements: Vec<Box<dyn Expression>>) -> Self {
@@ -720,9 +1744,37 @@ impl ArrayLiteral {
     }
 }
 
+pub struct SpreadElement {
+    argument: Box<dyn Expression>,
+}
+
+impl Statement for SpreadElement {
+    fn kind(&self) -> NodeType {
+        return NodeType::SpreadElementType;
+    }
+
+    fn to_string(&self) -> String {
+        return format!("...{}", self.argument.to_string());
+    }
+}
+
+impl Expression for SpreadElement {
+    fn into_pattern(self: Box<Self>) -> Option<Pattern> {
+        return Some(Pattern::Rest(Box::new(self.argument.into_pattern()?)));
+    }
+}
+
+impl SpreadElement {
+    pub fn new(argument: Box<dyn Expression>) -> Self {
+        return SpreadElement { argument };
+    }
+}
+
 pub struct CallExpression {
     callee: Box<dyn Expression>,
     arguments: Vec<Box<dyn Expression>>,
+    optional: bool,
+    optional_chain: bool,
 }
 
 impl Statement for CallExpression {
@@ -741,19 +1793,42 @@ impl Statement for CallExpression {
             }
         }
 
+        let optional_str = if self.optional { "?." } else { "" };
         return format!(
-            "{}({})",
+            "{}{}({})",
             self.callee.to_string(),
+            optional_str,
             arguments_str
         );
     }
 }
 
-impl Expression for CallExpression {}
+impl Expression for CallExpression {
+    fn is_optional_chain(&self) -> bool {
+        return self.optional_chain;
+    }
+
+    fn take_operands(&mut self, operands: &mut Vec<Box<dyn Expression>>) {
+        operands.push(take_operand(&mut self.callee));
+        operands.append(&mut self.arguments);
+    }
+}
+
+impl Drop for CallExpression {
+    fn drop(&mut self) {
+        drop_operands(self);
+    }
+}
 
 impl CallExpression {
-    pub fn new(callee: Box<dyn Expression>, arguments: Vec<Box<dyn Expression>>) -> Self {
-        return CallExpression { callee, arguments };
+    pub fn new(callee: Box<dyn Expression>, arguments: Vec<Box<dyn Expression>>, optional: bool) -> Self {
+        let optional_chain = optional || callee.is_optional_chain();
+        return CallExpression {
+            callee,
+            arguments,
+            optional,
+            optional_chain,
+        };
     }
 }
 
@@ -761,6 +1836,8 @@ pub struct MemberExpression {
     object: Box<dyn Expression>,
     property: Box<dyn Expression>,
     computed: bool,
+    optional: bool,
+    optional_chain: bool,
 }
 
 impl Statement for MemberExpression {
@@ -769,9 +1846,17 @@ impl Statement for MemberExpression {
     }
 
     fn to_string(&self) -> String {
+        let optional_str = if self.optional { "?." } else { "" };
         if self.computed {
             return format!(
-                "{}[{}]",
+                "{}{}[{}]",
+                self.object.to_string(),
+                optional_str,
+                self.property.to_string()
+            );
+        } else if self.optional {
+            return format!(
+                "{}?.{}",
                 self.object.to_string(),
                 self.property.to_string()
             );
@@ -785,14 +1870,40 @@ impl Statement for MemberExpression {
     }
 }
 
-impl Expression for MemberExpression {}
+impl Expression for MemberExpression {
+    fn into_pattern(self: Box<Self>) -> Option<Pattern> {
+        if self.is_optional_chain() {
+            return None;
+        }
+
+        return Some(Pattern::Member(self));
+    }
+
+    fn is_optional_chain(&self) -> bool {
+        return self.optional_chain;
+    }
+
+    fn take_operands(&mut self, operands: &mut Vec<Box<dyn Expression>>) {
+        operands.push(take_operand(&mut self.object));
+        operands.push(take_operand(&mut self.property));
+    }
+}
+
+impl Drop for MemberExpression {
+    fn drop(&mut self) {
+        drop_operands(self);
+    }
+}
 
 impl MemberExpression {
-    pub fn new(object: Box<dyn Expression>, property: Box<dyn Expression>, computed: bool) -> Self {
+    pub fn new(object: Box<dyn Expression>, property: Box<dyn Expression>, computed: bool, optional: bool) -> Self {
+        let optional_chain = optional || object.is_optional_chain();
         return MemberExpression {
             object,
             property,
             computed,
+            optional,
+            optional_chain,
         };
     }
 }
@@ -807,7 +1918,12 @@ impl Statement for ExpressionStatement {
     }
 
     fn to_string(&self) -> String {
-        return self.expression.to_string();
+        let expression = self.expression.to_string();
+        if expression.starts_with('{') {
+            return format!("({});\n", expression);
+        }
+
+        return format!("{};\n", expression);
     }
 }
 
@@ -818,3 +1934,152 @@ impl ExpressionStatement {
         return ExpressionStatement { expression };
     }
 }
+
+pub struct EmptyStatement {}
+
+impl Statement for EmptyStatement {
+    fn kind(&self) -> NodeType {
+        return NodeType::EmptyStatementType;
+    }
+
+    fn to_string(&self) -> String {
+        return String::from(";\n");
+    }
+}
+
+impl EmptyStatement {
+    pub fn new() -> Self {
+        return EmptyStatement {};
+    }
+}
+
+impl Default for EmptyStatement {
+    fn default() -> Self {
+        return EmptyStatement::new();
+    }
+}
+
+pub enum MatchPattern {
+    Literal(Box<dyn Expression>),
+    Binding(String),
+    Wildcard,
+    Array(Vec<MatchPattern>),
+    Object(Vec<(String, MatchPattern)>),
+    Variant {
+        path: Vec<String>,
+        fields: Option<Vec<MatchPattern>>,
+    },
+    Rest(String),
+}
+
+impl MatchPattern {
+    pub fn to_string(&self) -> String {
+        match self {
+            MatchPattern::Literal(value) => return value.to_string(),
+            MatchPattern::Binding(name) => return name.clone(),
+            MatchPattern::Wildcard => return String::from("_"),
+            MatchPattern::Array(elements) => {
+                let elements_str = elements
+                    .iter()
+                    .map(|elem| elem.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                return format!("[{}]", elements_str);
+            },
+            MatchPattern::Object(properties) => {
+                let properties_str = properties
+                    .iter()
+                    .map(|(key, pattern)| {
+                        if let MatchPattern::Binding(name) = pattern {
+                            if name == key {
+                                return key.clone();
+                            }
+                        }
+                        return format!("{}: {}", key, pattern.to_string());
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                return format!("{{{}}}", properties_str);
+            },
+            MatchPattern::Variant { path, fields } => {
+                let path_str = path.join("::");
+
+                match fields {
+                    Some(fields) => {
+                        let fields_str = fields
+                            .iter()
+                            .map(|field| field.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+
+                        return format!("{}({})", path_str, fields_str);
+                    },
+                    None => return path_str,
+                }
+            },
+            MatchPattern::Rest(name) => return format!("...{}", name),
+        }
+    }
+}
+
+pub enum MatchArmBody {
+    Expression(Box<dyn Expression>),
+    Block(BlockStatement),
+}
+
+pub struct MatchArm {
+    pattern: MatchPattern,
+    guard: Option<Box<dyn Expression>>,
+    body: MatchArmBody,
+}
+
+impl MatchArm {
+    pub fn new(pattern: MatchPattern, guard: Option<Box<dyn Expression>>, body: MatchArmBody) -> Self {
+        return MatchArm { pattern, guard, body };
+    }
+
+    pub fn to_string(&self) -> String {
+        let guard_str = match &self.guard {
+            Some(guard) => format!(" if {}", guard.to_string()),
+            None => String::new(),
+        };
+
+        let body_str = match &self.body {
+            MatchArmBody::Expression(expression) => expression.to_string(),
+            MatchArmBody::Block(body) => body.block_to_string(),
+        };
+
+        return format!("{}{} => {},\n", self.pattern.to_string(), guard_str, body_str);
+    }
+}
+
+pub struct MatchExpression {
+    subject: Box<dyn Expression>,
+    arms: Vec<MatchArm>,
+}
+
+impl Statement for MatchExpression {
+    fn kind(&self) -> NodeType {
+        return NodeType::MatchExpressionType;
+    }
+
+    fn to_string(&self) -> String {
+        let mut arms_str: String = String::new();
+
+        for arm in &self.arms {
+            arms_str += &arm.to_string();
+        }
+
+        return format!("match {} {{\n{}}}", self.subject.to_string(), arms_str);
+    }
+}
+
+impl Expression for MatchExpression {}
+
+impl MatchExpression {
+    pub fn new(subject: Box<dyn Expression>, arms: Vec<MatchArm>) -> Self {
+        return MatchExpression { subject, arms };
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ParseError {
+    Syntax { message: String, line: usize, column: usize },
+    UnexpectedEof { message: String, line: usize, column: usize },
+    TrailingInput { found: String, line: usize, column: usize },
+    TooDeep { line: usize, column: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Syntax { message, line, column } => {
+                return write!(f, "Error: {} at line {}, column {}", message, line, column);
+            },
+            ParseError::UnexpectedEof { message, line, column } => {
+                return write!(f, "Error: Unexpected end of input, {} at line {}, column {}", message, line, column);
+            },
+            ParseError::TrailingInput { found, line, column } => {
+                return write!(f, "Error: Unexpected trailing input '{}' at line {}, column {}", found, line, column);
+            },
+            ParseError::TooDeep { line, column } => {
+                return write!(f, "Error: Maximum nesting depth exceeded at line {}, column {}", line, column);
+            },
+        }
+    }
+}
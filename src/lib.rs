@@ -0,0 +1,4 @@
+pub mod error;
+mod tokenizer;
+pub mod parser;
+pub mod ast;
@@ -1,8 +1,5 @@
-use crate::ast::Statement;
-
-mod tokenizer;
-mod parser;
-mod ast;
+use rusty_app::ast::Statement;
+use rusty_app::parser;
 
 fn main() {
     let source_code = "
@@ -14,7 +11,10 @@ println(thisIsAVerySimpleInterpreter(5, 6));
 ";
     let mut parser = parser::Parser::new();
 
-    println!("{}", parser.produce_ast(source_code).to_string());
+    match parser.produce_ast(source_code) {
+        Ok(program) => println!("{}", program.to_string()),
+        Err(error) => eprintln!("{}", error),
+    }
 }
 
 // use rand::Rng;
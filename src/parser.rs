@@ -1,24 +1,56 @@
 use crate::tokenizer::{TokenType, Token};
 use crate::ast;
-use std::process::exit;
+use crate::error::ParseError;
+use std::collections::VecDeque;
+
+const DEFAULT_MAX_DEPTH: usize = 64;
+
+pub enum Completeness {
+    Complete,
+    Incomplete { expected: String },
+    Invalid(Vec<ParseError>),
+}
 
 pub struct Parser {
-    tokens: Vec<Token>,
+    tokens: VecDeque<Token>,
     is_function: bool,
     is_loop: bool,
+    is_derived_class: bool,
+    is_generator: bool,
+    is_match_guard: bool,
+    labels: Vec<String>,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        return Parser::new();
+    }
 }
 
 impl Parser {
     pub fn new() -> Self {
         Parser {
-            tokens: Vec::new(),
+            tokens: VecDeque::new(),
             is_function: false,
             is_loop: false,
+            is_derived_class: false,
+            is_generator: false,
+            is_match_guard: false,
+            labels: Vec::new(),
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
         }
     }
 
-    pub fn produce_ast(&mut self, source_code: &str) -> ast::Program {
-        self.tokens = crate::tokenizer::tokenize(source_code);
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        return self;
+    }
+
+    pub fn produce_ast(&mut self, source_code: &str) -> Result<ast::Program, ParseError> {
+        self.load(source_code)?;
         let mut program = ast::Program{
             body: Vec::new(),
         };
@@ -29,13 +61,82 @@ impl Parser {
                 continue;
             }
 
-            program.body.push(self.parse_statement());
+            if self.at().r#type == TokenType::Export {
+                program.body.push(self.parse_export_declaration()?);
+                continue;
+            }
+
+            program.body.push(self.parse_statement()?);
+        }
+
+        return Ok(program);
+    }
+
+    pub fn check_complete(&mut self, source_code: &str) -> Completeness {
+        let tokens = match crate::tokenizer::tokenize(source_code) {
+            Ok(tokens) => tokens,
+            Err(ParseError::UnexpectedEof { .. }) => return Completeness::Incomplete { expected: String::from("\"") },
+            Err(error) => return Completeness::Invalid(vec![error]),
+        };
+
+        let mut closers: Vec<&str> = Vec::new();
+        for token in &tokens {
+            if closers.last() == Some(&"*/") && token.r#type != TokenType::CloseComment {
+                continue;
+            }
+
+            match token.r#type {
+                TokenType::OpenParen => closers.push(")"),
+                TokenType::OpenBracket => closers.push("]"),
+                TokenType::LSquirly => closers.push("}"),
+                TokenType::OpenComment => closers.push("*/"),
+                TokenType::CloseParen | TokenType::CloseBracket | TokenType::RSquirly | TokenType::CloseComment => {
+                    closers.pop();
+                },
+                _ => {},
+            }
+        }
+
+        match self.produce_ast(source_code) {
+            Ok(_) => return Completeness::Complete,
+            Err(ParseError::UnexpectedEof { message, .. }) => {
+                let expected = match closers.last() {
+                    Some(closer) => closer.to_string(),
+                    None => message,
+                };
+                return Completeness::Incomplete { expected };
+            },
+            Err(error) => return Completeness::Invalid(vec![error]),
         }
+    }
+
+    pub fn parse_expression_str(&mut self, source_code: &str) -> Result<Box<dyn ast::Expression>, ParseError> {
+        self.load(source_code)?;
+        let expression = self.parse_expression()?;
+        self.expect_end()?;
+        return Ok(expression);
+    }
 
-        return program;
+    pub fn parse_statement_str(&mut self, source_code: &str) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.load(source_code)?;
+        let statement = self.parse_statement()?;
+        self.expect_end()?;
+        return Ok(statement);
+    }
+
+    fn load(&mut self, source_code: &str) -> Result<(), ParseError> {
+        self.tokens = crate::tokenizer::tokenize(source_code)?.into();
+        self.is_function = false;
+        self.is_loop = false;
+        self.is_derived_class = false;
+        self.is_generator = false;
+        self.is_match_guard = false;
+        self.labels.clear();
+        self.depth = 0;
+        return Ok(());
     }
 
-    fn parse_statement(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         match self.at().r#type {
             TokenType::OpenComment => return self.parse_comment(),
             TokenType::Let => return self.parse_variable_declaration(),
@@ -43,581 +144,2377 @@ impl Parser {
             TokenType::Function => return self.parse_function_declaration(),
             TokenType::Return => {
                 if !self.is_function {
-                    eprintln!("Error: Return statement must be inside a function");
-                    exit(0);
+                    return Err(self.error(String::from("Return statement must be inside a function")));
                 }
                 return self.parse_return_statement();
             },
             TokenType::Class => return self.parse_class_declaration(),
+            TokenType::Enum => return self.parse_enum_declaration(),
             TokenType::Break => {
                 if !self.is_loop {
-                    eprintln!("Error: Break statement must be inside a loop");
-                    exit(0);
+                    return Err(self.error(String::from("Break statement must be inside a loop")));
                 }
 
                 return self.parse_break_statement();
             },
+            TokenType::Continue => {
+                if !self.is_loop {
+                    return Err(self.error(String::from("Continue statement must be inside a loop")));
+                }
+
+                return self.parse_continue_statement();
+            },
+            TokenType::Label => return self.parse_labeled_statement(),
             TokenType::If => return self.parse_if_statement(),
-            TokenType::Else => return self.parse_if_statement(),
+            TokenType::LSquirly => return Ok(Box::new(self.parse_block()?)),
+            TokenType::Else => {
+                return Err(self.error(String::from("Unexpected else without a matching if")));
+            },
             TokenType::While => return self.parse_while_statement(),
             TokenType::Loop => return self.parse_loop_statement(),
             TokenType::ForEach => return self.parse_for_each_statement(),
             TokenType::For => return self.parse_for_statement(),
-            TokenType::Import => return self.parse_import_statement(),
+            TokenType::Identifier if self.at().value == "type" && self.peek().r#type == TokenType::Identifier => {
+                return self.parse_type_alias_declaration();
+            },
+            TokenType::Try => return self.parse_try_statement(),
+            TokenType::Catch | TokenType::Finally => {
+                return Err(self.error(format!("Unexpected {} without a matching try", self.at().value)));
+            },
+            TokenType::Throw => return self.parse_throw_statement(),
+            TokenType::Match => {
+                let expression = self.parse_match_expression()?;
+                return Ok(Box::new(ast::ExpressionStatement::new(expression)));
+            },
+            TokenType::Import => return self.parse_import_declaration(),
+            TokenType::Export => {
+                return Err(self.error(String::from("Export declarations are only allowed at the top level")));
+            },
             TokenType::SemiColon => {
                 self.eat();
-                return Box::new(ast::NullLiteral::new());
+                return Ok(Box::new(ast::EmptyStatement::new()));
             },
             _ => {
-                let expression = self.parse_expression();
-                return Box::new(ast::ExpressionStatement::new(expression));
+                let expression = self.parse_expression()?;
+                self.expect_terminator()?;
+                return Ok(Box::new(ast::ExpressionStatement::new(expression)));
             },
             // _ => return self.parse_expression(),
         };
     }
 
-    fn parse_import_statement(&mut self) -> Box<dyn ast::Statement> {
-        self.eat();
-        let path = self.expect(TokenType::String, "Error: Expected string after import keyword".to_string()).value;
-        return Box::new(ast::ImportStatement::new(path));
-    }
-
-    fn parse_for_statement(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_import_declaration(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        self.is_loop = true;
-
-        let init = self.parse_statement();
-        self.expect(TokenType::SemiColon, "Error: Expected ; after for init".to_string());
-        let condition = self.parse_expression();
-        self.expect(TokenType::SemiColon, "Error: Expected ; after for condition".to_string());
-        let increment = self.parse_expression();
 
-        self.expect(TokenType::LSquirly, "Error: Expected { after for increment".to_string());
+        if self.at().r#type == TokenType::String {
+            let source = self.eat().value;
+            self.expect_terminator()?;
 
-        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
-        while self.at().r#type != TokenType::RSquirly {
-            body.push(self.parse_statement());
+            return Ok(Box::new(ast::ImportDeclaration::new(Vec::new(), source)));
         }
 
-        self.expect(TokenType::RSquirly, "Error: Expected } after for body".to_string());
+        let mut specifiers: Vec<ast::ImportSpecifier> = Vec::new();
+        if self.at().value == "*" {
+            self.eat();
+            self.expect_contextual("as", "Expected as after * in import".to_string())?;
+            let local = self.expect(TokenType::Identifier, "Expected namespace name after as".to_string())?.value;
+            specifiers.push(ast::ImportSpecifier::Namespace { local });
+        } else {
+            self.expect(TokenType::LSquirly, "Expected string, { or * after import keyword".to_string())?;
+
+            while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+                let imported = self.expect(TokenType::Identifier, "Expected imported name".to_string())?.value;
+                let mut local = imported.clone();
 
-        self.is_loop = false;
-        return Box::new(ast::ForStatement::new(init, condition, increment, body));
-    }
+                if self.at().r#type == TokenType::Identifier && self.at().value == "as" {
+                    self.eat();
+                    local = self.expect(TokenType::Identifier, "Expected local name after as".to_string())?.value;
+                }
 
-    fn parse_for_each_statement(&mut self) -> Box<dyn ast::Statement> {
-        self.eat();
-        self.is_loop = true;
+                if specifiers.iter().any(|specifier| specifier.local() == local) {
+                    return Err(self.error(format!("Duplicate import name '{}'", local)));
+                }
 
-        let identifier = self.expect(TokenType::Identifier, "Error: Expected identifier after for keyword".to_string()).value;
-        self.expect(TokenType::In, "Error: Expected in keyword after for identifier".to_string());
-        let expression = self.parse_expression();
+                specifiers.push(ast::ImportSpecifier::Named { imported, local });
 
-        self.expect(TokenType::LSquirly, "Error: Expected { after for expression".to_string());
+                if self.at().r#type != TokenType::RSquirly {
+                    self.expect(TokenType::Comma, "Expected , between import specifiers".to_string())?;
+                }
+            }
 
-        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
-        while self.at().r#type != TokenType::RSquirly {
-            body.push(self.parse_statement());
+            self.expect(TokenType::RSquirly, "Expected } after import specifiers".to_string())?;
         }
 
-        self.expect(TokenType::RSquirly, "Error: Expected } after for body".to_string());
+        self.expect_contextual("from", "Expected from after import specifiers".to_string())?;
+        let source = self.expect(TokenType::String, "Expected module path after from".to_string())?.value;
 
-        self.is_loop = false;
-        return Box::new(ast::ForEachStatement::new(identifier, expression, body));
+        self.expect_terminator()?;
+
+        return Ok(Box::new(ast::ImportDeclaration::new(specifiers, source)));
     }
 
-    fn parse_loop_statement(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_export_declaration(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        self.expect(TokenType::LSquirly, "Error: Expected { after loop keyword".to_string());
 
-        self.is_loop = true;
-        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
-        while self.at().r#type != TokenType::RSquirly {
-            body.push(self.parse_statement());
-        }
+        match self.at().r#type {
+            TokenType::Let | TokenType::Constant | TokenType::Class | TokenType::Enum => {},
+            TokenType::Function => {
+                let mut name = self.peek();
+                if name.r#type == TokenType::BinaryOperator && name.value == "*" {
+                    name = &self.tokens[2];
+                }
 
-        self.expect(TokenType::RSquirly, "Error: Expected } after loop body".to_string());
+                if name.r#type != TokenType::Identifier {
+                    return Err(self.unexpected(String::from("Exported functions must have a name")));
+                }
+            },
+            _ => {
+                return Err(self.unexpected(String::from("Expected let, const, func, class or enum after export keyword")));
+            }
+        }
 
-        self.is_loop = false;
-        return Box::new(ast::LoopStatement::new(body));
+        let declaration = self.parse_statement()?;
+        return Ok(Box::new(ast::ExportDeclaration::new(declaration)));
     }
 
-    fn parse_while_statement(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_for_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        let condition = self.parse_expression();
-        self.expect(TokenType::LSquirly, "Error: Expected { after while condition".to_string());
-
+        let was_loop = self.is_loop;
         self.is_loop = true;
-        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
-        while self.at().r#type != TokenType::RSquirly {
-            body.push(self.parse_statement());
-        }
 
-        self.expect(TokenType::RSquirly, "Error: Expected } after while body".to_string());
+        let init = self.parse_statement()?;
+        let condition = self.parse_expression()?;
+        self.expect(TokenType::SemiColon, "Expected ; after for condition".to_string())?;
+        let increment = self.parse_expression()?;
 
-        self.is_loop = false;
-        return Box::new(ast::WhileStatement::new(condition, body));
+        let body = self.parse_block()?;
+
+        self.is_loop = was_loop;
+        return Ok(Box::new(ast::ForStatement::new(init, condition, increment, body)));
     }
 
-    fn parse_if_statement(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_for_each_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        let condition = self.parse_expression();
-        self.expect(TokenType::LSquirly, "Error: Expected { after if condition".to_string());
-
-        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
-        while self.at().r#type != TokenType::RSquirly {
-            body.push(self.parse_statement());
-        }
-
-        self.expect(TokenType::RSquirly, "Error: Expected } after if body".to_string());
-
-        if self.at().r#type == TokenType::Else {
-            self.eat();
-            self.expect(TokenType::LSquirly, "Error: Expected { after else keyword".to_string());
-
-            let mut else_body: Vec<Box<dyn ast::Statement>> = Vec::new();
-            while self.at().r#type != TokenType::RSquirly {
-                else_body.push(self.parse_statement());
-            }
+        let was_loop = self.is_loop;
+        self.is_loop = true;
 
-            self.expect(TokenType::RSquirly, "Error: Expected } after else body".to_string());
+        let variable = self.parse_binding_pattern()?;
+        self.expect(TokenType::In, "Expected in keyword after for identifier".to_string())?;
+        let expression = self.parse_expression()?;
 
-            return Box::new(ast::ConditionalStatement::new(condition, body, else_body));
-        }
+        let body = self.parse_block()?;
 
-        return Box::new(ast::ConditionalStatement::new(condition, body, Vec::new()));
+        self.is_loop = was_loop;
+        return Ok(Box::new(ast::ForEachStatement::new(variable, expression, body)));
     }
 
-    fn parse_break_statement(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_loop_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        if self.at().r#type == TokenType::SemiColon {
-            self.eat();
-        }
+        let was_loop = self.is_loop;
+        self.is_loop = true;
+
+        let body = self.parse_block()?;
 
-        return Box::new(ast::BreakStatement::new());
+        self.is_loop = was_loop;
+        return Ok(Box::new(ast::LoopStatement::new(body)));
     }
 
-    fn parse_class_declaration(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_while_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        let name = self.expect(TokenType::Identifier, "Error: Expected class name after class keyword".to_string()).value;
-        self.expect(TokenType::LSquirly, "Error: Expected { after class name".to_string());
-
-        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
-        while self.at().r#type != TokenType::RSquirly {
-            body.push(self.parse_statement());
-        }
+        let condition = self.parse_expression()?;
+        let was_loop = self.is_loop;
+        self.is_loop = true;
 
-        self.expect(TokenType::RSquirly, "Error: Expected } after class declaration".to_string());
+        let body = self.parse_block()?;
 
-        return Box::new(ast::ClassDeclaration::new(name, body));
+        self.is_loop = was_loop;
+        return Ok(Box::new(ast::WhileStatement::new(condition, body)));
     }
 
-    fn parse_return_statement(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_if_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        let expression = self.parse_expression();
+        let condition = self.parse_expression()?;
+        let body = self.parse_block()?;
 
-        if self.at().r#type == TokenType::SemiColon {
-            self.eat();
-        }
-
-        return Box::new(ast::ReturnStatement::new(expression));
-    }
+        let mut branches: Vec<(Box<dyn ast::Expression>, ast::BlockStatement)> = Vec::new();
+        let mut alternate: Option<Box<dyn ast::Statement>> = None;
 
-    fn parse_comment(&mut self) -> Box<dyn ast::Statement> {
-        while self.at().r#type != TokenType::CloseComment {
+        while self.at().r#type == TokenType::Else {
             self.eat();
-        }
-        self.expect(TokenType::CloseComment, "Error: Expected close comment".to_string());
-        return Box::new(ast::NullLiteral::new());
-    }
-    
-    fn parse_variable_declaration(&mut self) -> Box<dyn ast::Statement> {
-        let is_constant = self.eat().r#type == TokenType::Constant;
-        let identifier = self.expect(TokenType::Identifier, "Error: Expected identifier".to_string()).value;
 
-        if self.at().r#type == TokenType::SemiColon {
-            self.eat();
-            if is_constant {
-                eprintln!("Error: Constant variable '{}' must be initialized", identifier);
-                exit(0);
+            if self.at().r#type != TokenType::If {
+                alternate = Some(Box::new(self.parse_block()?));
+                break;
             }
 
-            return Box::new(ast::VariableDeclaration::new(is_constant, identifier, Box::new(ast::NullLiteral::new())));
+            self.eat();
+            let else_if_condition = self.parse_expression()?;
+            let else_if_body = self.parse_block()?;
+            branches.push((else_if_condition, else_if_body));
         }
 
-        self.expect(TokenType::Equals, "Error: Expected assignment".to_string());
-        let declaration = Box::new(ast::VariableDeclaration::new(is_constant, identifier, self.parse_expression()));
-
-        if !self.is_loop {
-            if self.at().r#type == TokenType::SemiColon {
-                self.eat();
-            }
+        while let Some((else_if_condition, else_if_body)) = branches.pop() {
+            alternate = Some(Box::new(ast::ConditionalStatement::new(else_if_condition, else_if_body, alternate)));
         }
 
-        return declaration;
+        return Ok(Box::new(ast::ConditionalStatement::new(condition, body, alternate)));
     }
 
-    fn parse_function_declaration(&mut self) -> Box<dyn ast::Statement> {
+    fn parse_break_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        let mut name: String = "".to_string();
+        let label = self.parse_jump_label()?;
 
-        if self.at().r#type != TokenType::OpenParen {
-            name = self.expect(TokenType::Identifier, "Error: Expected function name after fn keyword".to_string()).value
-        }
+        self.expect_terminator()?;
 
-        let args: Vec<Box<dyn ast::Expression>> = self.parse_args();
-        let mut params: Vec<String> = vec![];
+        return Ok(Box::new(ast::BreakStatement::new(label)));
+    }
 
-        for arg in args {
-            match arg.kind() {
-                ast::NodeType::IdentifierType => {
-                    params.push(arg.to_string());
-                },
-                _ => {
-                    eprintln!("Error: Expected identifier");
-                    exit(0);
-                }
-            }
-        }
+    fn parse_continue_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.eat();
+        let label = self.parse_jump_label()?;
 
-        self.expect(TokenType::LSquirly, "Error: Expected open curly brace".to_string());
+        self.expect_terminator()?;
 
-        if !self.is_function {
-            self.is_function = true;
-        }
+        return Ok(Box::new(ast::ContinueStatement::new(label)));
+    }
 
-        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
-        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
-            body.push(self.parse_statement());
+    fn parse_jump_label(&mut self) -> Result<Option<String>, ParseError> {
+        if self.at().r#type != TokenType::Label {
+            return Ok(None);
         }
 
-        self.expect(TokenType::RSquirly, "Error: Expected close curly brace".to_string());
+        let label = self.eat().value;
+        if !self.labels.contains(&label) {
+            return Err(self.error(format!("Undefined label '{}'", label)));
+        }
 
-        self.is_function = false;
-        return Box::new(ast::FunctionDeclaration::new(params, name, body, false));
+        return Ok(Some(label));
     }
 
-    fn parse_args(&mut self) -> Vec<Box<dyn ast::Expression>> {
-        self.expect(TokenType::OpenParen, "Error: Expected open parenthesis".to_string());
+    fn parse_labeled_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        let label = self.eat().value;
+        self.expect(TokenType::Colon, "Expected : after label".to_string())?;
 
-        let args: Vec<Box<dyn ast::Expression>>;
-        if self.at().r#type == TokenType::CloseParen {
-            args = Vec::new();
-        } else {
-            args = self.parse_args_list();
+        match self.at().r#type {
+            TokenType::While | TokenType::Loop | TokenType::For | TokenType::ForEach => {},
+            _ => {
+                return Err(self.unexpected(format!("Label '{}' must be followed by a loop", label)));
+            }
         }
 
-        self.expect(TokenType::CloseParen, "Error: Expected close parenthesis".to_string());
-        return args;
-    }
-
-    fn parse_args_list(&mut self) -> Vec<Box<dyn ast::Expression>> {
-        let mut args = vec![self.parse_assignment_expression()];
-
-        while self.at().r#type == TokenType::Comma {
-            self.eat();
-            args.push(self.parse_assignment_expression());
+        if self.labels.contains(&label) {
+            return Err(self.error(format!("Label '{}' is already declared", label)));
         }
 
-        return args;
+        self.labels.push(label.clone());
+        let body = self.parse_statement()?;
+        self.labels.pop();
+
+        return Ok(Box::new(ast::LabeledStatement::new(label, body)));
     }
 
-    fn parse_assignment_expression(&mut self) -> Box<dyn ast::Expression> {
-        let left = self.parse_or_expression();
+    fn parse_try_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.eat();
+        let block = self.parse_block()?;
 
-        if self.at().r#type == TokenType::Equals {
+        let mut handler: Option<ast::CatchClause> = None;
+        if self.at().r#type == TokenType::Catch {
             self.eat();
-            let value = self.parse_assignment_expression();
-
-            return Box::new(ast::AssignmentExpression::new(left, value));
-        }
 
-        return left;
-    }
+            let mut param: Option<ast::Pattern> = None;
+            if self.at().r#type == TokenType::OpenParen {
+                self.eat();
+                param = Some(self.parse_binding_pattern()?);
+                self.expect(TokenType::CloseParen, "Expected ) after catch parameter".to_string())?;
+            }
 
-    fn parse_or_expression(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_and_expression();
+            let body = self.parse_block()?;
+            handler = Some(ast::CatchClause::new(param, body));
+        }
 
-        while self.at().r#type == TokenType::Or {
+        let mut finalizer: Option<ast::BlockStatement> = None;
+        if self.at().r#type == TokenType::Finally {
             self.eat();
-            let right = self.parse_and_expression();
+            finalizer = Some(self.parse_block()?);
+        }
 
-            left = Box::new(ast::LogicalExpression::new(left, right, "or".to_string()));
+        if handler.is_none() && finalizer.is_none() {
+            return Err(self.unexpected(String::from("Try statement must have a catch or finally block")));
         }
 
-        return left;
+        return Ok(Box::new(ast::TryStatement::new(block, handler, finalizer)));
     }
 
-    fn parse_and_expression(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_xor_expression();
-
-        while self.at().r#type == TokenType::And {
-            self.eat();
-            let right = self.parse_xor_expression();
+    fn parse_throw_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.eat();
+        let argument = self.parse_expression()?;
 
-            left = Box::new(ast::LogicalExpression::new(left, right, "and".to_string()));
-        }
+        self.expect_terminator()?;
 
-        return left;
+        return Ok(Box::new(ast::ThrowStatement::new(argument)));
     }
 
-    fn parse_xor_expression(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_not_expression();
+    fn parse_class_declaration(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.eat();
+        let name = self.expect(TokenType::Identifier, "Expected class name after class keyword".to_string())?.value;
 
-        while self.at().r#type == TokenType::Xor {
+        let mut superclass: Option<String> = None;
+        if self.at().r#type == TokenType::Extends {
             self.eat();
-            let right = self.parse_not_expression();
-
-            left = Box::new(ast::LogicalExpression::new(left, right, "xor".to_string()));
+            superclass = Some(self.expect(TokenType::Identifier, "Expected class name after extends keyword".to_string())?.value);
         }
 
-        return left;
-    }
-
-    fn parse_not_expression(&mut self) -> Box<dyn ast::Expression> {
-        if self.at().r#type == TokenType::Not {
-            self.eat();
-            let expression = self.parse_not_expression();
-
-            return Box::new(ast::LogicalExpression{right: expression, operator: "not".to_string(), left: Box::new(ast::NullLiteral::new())});
-        }
+        self.expect(TokenType::LSquirly, "Expected { after class name".to_string())?;
 
-        return self.parse_comparison_expression();
-    }
+        let was_derived_class = self.is_derived_class;
+        self.is_derived_class = superclass.is_some();
 
-    fn parse_comparison_expression(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_object_expression();
+        let mut members: Vec<ast::ClassMember> = Vec::new();
+        let mut has_constructor = false;
+        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+            let member = self.parse_class_member()?;
 
-        while self.at().value == ">" || self.at().value == "<" || (self.at().value == "=" && self.peek().value == "=") || self.at().value == "!=" {
-            let mut operator = self.eat().value;
-            if self.at().value == "=" {
-                operator += self.eat().value.as_str();
+            if let ast::ClassMember::Constructor(_) = member {
+                if has_constructor {
+                    return Err(self.error(format!("Class '{}' can only have one constructor", name)));
+                }
+                has_constructor = true;
             }
 
-            let right = self.parse_object_expression();
-
-            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+            members.push(member);
         }
 
-        return left;
-    }
+        self.expect(TokenType::RSquirly, "Expected } after class declaration".to_string())?;
 
-    fn parse_object_expression(&mut self) -> Box<dyn ast::Expression> {
-        if self.at().r#type != TokenType::LSquirly {
-            return self.parse_array_expression();
-        }
+        self.is_derived_class = was_derived_class;
+        return Ok(Box::new(ast::ClassDeclaration::new(name, superclass, members)));
+    }
 
+    fn parse_enum_declaration(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
         self.eat();
-        let mut properties: Vec<ast::Property> = Vec::new();
+        let name = self.expect(TokenType::Identifier, "Expected enum name after enum keyword".to_string())?.value;
+        self.expect(TokenType::LSquirly, "Expected { after enum name".to_string())?;
 
-        while self.is_not_eof() && self.at().r#type != TokenType::RSquirly {
-            let key = self.expect(TokenType::Identifier, "Expected identifier as object key".to_string()).value;
+        let mut variants: Vec<ast::EnumVariant> = Vec::new();
+        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+            let variant = self.expect(TokenType::Identifier, "Expected variant name in enum".to_string())?.value;
+            if variants.iter().any(|existing| existing.name == variant) {
+                return Err(self.error(format!("Duplicate variant '{}' in enum '{}'", variant, name)));
+            }
 
-            if self.at().r#type == TokenType::Comma {
+            let mut value: Option<Box<dyn ast::Expression>> = None;
+            let mut fields: Option<Vec<String>> = None;
+            if self.at().r#type == TokenType::Equals {
                 self.eat();
-                properties.push(ast::Property::new(key, Box::new(ast::NullLiteral::new())));
-                continue;
-            } else if self.at().r#type == TokenType::RSquirly {
-                properties.push(ast::Property::new(key, Box::new(ast::NullLiteral::new())));
-                continue;
+                value = Some(self.parse_expression()?);
+            } else if self.at().r#type == TokenType::OpenParen {
+                self.eat();
+                let mut names: Vec<String> = Vec::new();
+                while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::CloseParen {
+                    let field = self.expect(TokenType::Identifier, "Expected field name in enum variant".to_string())?.value;
+                    if names.contains(&field) {
+                        return Err(self.error(format!("Duplicate field '{}' in variant '{}'", field, variant)));
+                    }
+                    names.push(field);
+
+                    if self.at().r#type != TokenType::CloseParen {
+                        self.expect(TokenType::Comma, "Expected , between variant fields".to_string())?;
+                    }
+                }
+                self.expect(TokenType::CloseParen, "Expected ) after variant fields".to_string())?;
+                fields = Some(names);
             }
 
-            self.expect(TokenType::Colon, "Expected : after object key".to_string());
-            let value = self.parse_expression();
-            properties.push(ast::Property::new(key, value));
+            variants.push(ast::EnumVariant::new(variant, value, fields));
 
             if self.at().r#type != TokenType::RSquirly {
-                self.expect(TokenType::Comma, "Expected , after object property".to_string());
+                self.expect(TokenType::Comma, "Expected , after enum variant".to_string())?;
             }
         }
 
-        self.expect(TokenType::RSquirly, "Object literal must end with a }".to_string());
-        return Box::new(ast::ObjectLiteral::new(properties));
+        self.expect(TokenType::RSquirly, "Expected } after enum declaration".to_string())?;
+        return Ok(Box::new(ast::EnumDeclaration::new(name, variants)));
     }
 
-    fn parse_array_expression(&mut self) -> Box<dyn ast::Expression> {
-        if self.at().r#type != TokenType::OpenBracket {
-            return self.parse_bitwise();
+    fn parse_class_member(&mut self) -> Result<ast::ClassMember, ParseError> {
+        let mut is_static = false;
+        if self.at().r#type == TokenType::Static {
+            self.eat();
+            is_static = true;
         }
-        self.eat();
-        let mut elements: Vec<Box<dyn ast::Expression>> = Vec::new();
-        while self.at().r#type != TokenType::CloseBracket {
-            elements.push(self.parse_expression());
-            if self.at().r#type == TokenType::Comma {
-                self.eat();
-            }
+
+        let mut is_method = false;
+        if self.at().r#type == TokenType::Function {
+            self.eat();
+            is_method = true;
         }
-        self.expect(TokenType::CloseBracket, "Expected closing bracket after array expression".to_string());
-        return Box::new(ast::ArrayLiteral::new(elements));
-    }
 
-    fn parse_bitwise(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_bitwise_shift_bit();
+        let name = self.expect(TokenType::Identifier, "Expected class member name".to_string())?.value;
 
-        while self.at().value == "&" || self.at().value == "|" || self.at().value == "^" {
-            let operator = self.eat().value;
+        if is_method || self.at().r#type == TokenType::OpenParen {
+            let params = self.parse_params()?;
+            let return_type = self.parse_type_annotation()?;
+            let body = self.parse_function_body(false, self.is_derived_class)?;
+            let function = ast::FunctionExpression::new(params, return_type, Some(name.clone()), body, false, false);
 
-            let right = self.parse_bitwise_shift_bit();
+            if name == "constructor" {
+                if is_static {
+                    return Err(self.error(String::from("Constructor cannot be static")));
+                }
 
-            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+                return Ok(ast::ClassMember::Constructor(function));
+            }
+
+            return Ok(ast::ClassMember::Method { name, function, is_static });
         }
 
-        return left
-    }
+        let type_annotation = self.parse_type_annotation()?;
+
+        let mut value: Option<Box<dyn ast::Expression>> = None;
+        if self.at().r#type == TokenType::Equals {
+            self.eat();
+            value = Some(self.parse_expression()?);
+        }
 
-    fn parse_bitwise_shift_bit(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_additive_expression();
+        self.expect_terminator()?;
 
-        while self.at().value == "<<" || self.at().value == ">>" || self.at().value == ">>>" {
-            let operator = self.eat().value;
+        return Ok(ast::ClassMember::Field { name, type_annotation, value, is_static });
+    }
 
-            let right = self.parse_additive_expression();
+    fn parse_return_statement(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.eat();
 
-            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+        let mut expression: Box<dyn ast::Expression> = Box::new(ast::NullLiteral::new());
+        if self.at().r#type != TokenType::SemiColon {
+            expression = self.parse_expression()?;
         }
 
-        return left
+        self.expect_terminator()?;
+
+        return Ok(Box::new(ast::ReturnStatement::new(expression)));
     }
 
-    fn parse_additive_expression(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_multiplicative_expression();
+    fn parse_comment(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        while self.is_not_eof() && self.at().r#type != TokenType::CloseComment {
+            self.eat();
+        }
+        self.expect(TokenType::CloseComment, "Expected close comment".to_string())?;
+        return Ok(Box::new(ast::NullLiteral::new()));
+    }
+    
+    fn parse_variable_declaration(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        let is_constant = self.eat().r#type == TokenType::Constant;
+        let pattern = self.parse_binding_pattern()?;
+        let type_annotation = self.parse_type_annotation()?;
 
-        while self.at().value == "+" || self.at().value == "-" {
-            let operator = self.eat().value;
+        if self.at().r#type == TokenType::SemiColon {
+            self.eat();
+            if is_constant {
+                return Err(self.error(format!("Constant variable '{}' must be initialized", pattern.to_string())));
+            }
 
-            let right = self.parse_multiplicative_expression();
+            if let ast::Pattern::Array(_) | ast::Pattern::Object { .. } = pattern {
+                return Err(self.error(format!("Destructuring declaration '{}' must be initialized", pattern.to_string())));
+            }
 
-            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+            return Ok(Box::new(ast::VariableDeclaration::new(is_constant, pattern, type_annotation, Box::new(ast::NullLiteral::new()))));
         }
 
-        return left
+        self.expect(TokenType::Equals, "Expected assignment".to_string())?;
+        let declaration = Box::new(ast::VariableDeclaration::new(is_constant, pattern, type_annotation, self.parse_expression()?));
+        self.expect_terminator()?;
+
+        return Ok(declaration);
     }
 
-    fn parse_multiplicative_expression(&mut self) -> Box<dyn ast::Expression> {
-        let mut left = self.parse_call_member_expression();
+    fn parse_type_alias_declaration(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.eat();
+        let name = self.expect(TokenType::Identifier, "Expected type name after type keyword".to_string())?.value;
+        self.expect(TokenType::Equals, "Expected = after type name".to_string())?;
+        let type_expr = self.parse_type()?;
 
-        while self.at().value == "*" || self.at().value == "/" || self.at().value == "%" || self.at().value == "**" || self.at().value == "//" {
-            let operator = self.eat().value;
-            
-            let right = self.parse_call_member_expression();
+        self.expect_terminator()?;
 
-            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+        return Ok(Box::new(ast::TypeAliasDeclaration::new(name, type_expr)));
+    }
+
+    fn parse_type_annotation(&mut self) -> Result<Option<ast::TypeExpr>, ParseError> {
+        if self.at().r#type != TokenType::Colon {
+            return Ok(None);
         }
 
-        return left
+        self.eat();
+        return Ok(Some(self.parse_type()?));
     }
 
-    fn parse_call_member_expression(&mut self) -> Box<dyn ast::Expression> {
-        let member = self.parse_member_expression();
+    fn parse_type(&mut self) -> Result<ast::TypeExpr, ParseError> {
+        let mut types = vec![self.parse_postfix_type()?];
+
+        while self.at().r#type == TokenType::BinaryOperator && self.at().value == "|" {
+            self.eat();
+            types.push(self.parse_postfix_type()?);
+        }
 
-        while self.at().r#type == TokenType::OpenParen {
-            return self.parse_call_expression(member);
+        if types.len() == 1 {
+            return Ok(types.remove(0));
         }
 
-        return member;
+        return Ok(ast::TypeExpr::Union(types));
     }
 
-    fn parse_call_expression(&mut self, member: Box<dyn ast::Expression>) -> Box<dyn ast::Expression> {
-        let mut call_expression: Box<dyn ast::Expression> = Box::new(ast::CallExpression::new(member, self.parse_args()));
+    fn parse_postfix_type(&mut self) -> Result<ast::TypeExpr, ParseError> {
+        let mut type_expr = self.parse_primary_type()?;
 
-        if self.at().r#type == TokenType::OpenParen {
-            call_expression = self.parse_call_expression(call_expression);
+        loop {
+            if self.at().r#type == TokenType::OpenBracket && self.peek().r#type == TokenType::CloseBracket {
+                self.eat();
+                self.eat();
+                type_expr = ast::TypeExpr::Array(Box::new(type_expr));
+            } else if self.at().r#type == TokenType::Question {
+                self.eat();
+                type_expr = ast::TypeExpr::Optional(Box::new(type_expr));
+            } else {
+                break;
+            }
         }
 
-        return call_expression;
+        return Ok(type_expr);
     }
 
-    fn parse_member_expression(&mut self) -> Box<dyn ast::Expression> {
-        let mut object = self.parse_primary_expression();
-
-        while self.at().r#type == TokenType::Dot || self.at().r#type == TokenType::OpenBracket {
-            let operator = self.eat();
-            let property: Box<dyn ast::Expression>;
-            let computed: bool;
+    fn parse_primary_type(&mut self) -> Result<ast::TypeExpr, ParseError> {
+        match self.at().r#type {
+            TokenType::Identifier => return Ok(ast::TypeExpr::Named(self.eat().value)),
+            TokenType::LSquirly => {
+                self.eat();
+                let mut fields: Vec<(String, ast::TypeExpr)> = Vec::new();
 
-            if operator.r#type == TokenType::Dot {
-                computed = false;
-                property = self.parse_primary_expression();
+                while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+                    let name = self.expect(TokenType::Identifier, "Expected field name in object type".to_string())?.value;
+                    self.expect(TokenType::Colon, "Expected : after field name in object type".to_string())?;
+                    fields.push((name, self.nested(Self::parse_type)?));
 
-                match property.kind() {
-                    ast::NodeType::IdentifierType => {},
-                    _ => {
-                        eprintln!("Expected identifier .");
-                        exit(0);
+                    if self.at().r#type != TokenType::RSquirly {
+                        self.expect(TokenType::Comma, "Expected , between object type fields".to_string())?;
                     }
                 }
-            } else {
-                computed = true;
-                property = self.parse_expression();
-                self.expect(TokenType::CloseBracket, "Expected closing bracket after computed property".to_string());
+
+                self.expect(TokenType::RSquirly, "Expected } after object type".to_string())?;
+                return Ok(ast::TypeExpr::Object(fields));
+            },
+            TokenType::OpenParen => {
+                self.eat();
+                let mut params: Vec<ast::TypeExpr> = Vec::new();
+
+                while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::CloseParen {
+                    params.push(self.nested(Self::parse_type)?);
+
+                    if self.at().r#type != TokenType::CloseParen {
+                        self.expect(TokenType::Comma, "Expected , between function type parameters".to_string())?;
+                    }
+                }
+
+                self.expect(TokenType::CloseParen, "Expected ) after function type parameters".to_string())?;
+
+                if self.at().r#type == TokenType::Arrow {
+                    self.eat();
+                    return Ok(ast::TypeExpr::Function(params, Box::new(self.nested(Self::parse_type)?)));
+                }
+
+                if params.len() == 1 {
+                    return Ok(params.remove(0));
+                }
+
+                return Err(self.unexpected(String::from("Expected => after function type parameters")));
+            },
+            _ => {
+                return Err(self.unexpected(String::from("Expected type")));
             }
+        }
+    }
+
+    fn parse_function_declaration(&mut self) -> Result<Box<dyn ast::Statement>, ParseError> {
+        self.eat();
+        let is_generator = self.parse_generator_marker();
+        let mut name: String = "".to_string();
 
-            object = Box::new(ast::MemberExpression::new(object, property, computed));
+        if self.at().r#type != TokenType::OpenParen {
+            name = self.expect(TokenType::Identifier, "Expected function name after fn keyword".to_string())?.value
         }
 
-        return object;
+        let params = self.parse_params()?;
+        let return_type = self.parse_type_annotation()?;
+        let body = self.parse_function_body(is_generator, false)?;
+
+        return Ok(Box::new(ast::FunctionDeclaration::new(params, return_type, name, body, false, is_generator)));
     }
 
-    fn parse_primary_expression(&mut self) -> Box<dyn ast::Expression> {
-        let token = self.at().r#type;
+    fn parse_function_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        self.eat();
+        let is_generator = self.parse_generator_marker();
+        let mut name: Option<String> = None;
 
-        match token {
-            TokenType::Identifier => {
-                return Box::new(ast::Identifier::new(self.eat().value));
-            },
-            TokenType::Number => {
-                return Box::new(ast::NumericLiteral::new(self.eat().value.parse::<f64>().unwrap()));
-            },
-            TokenType::String => {
-                return Box::new(ast::StringLiteral::new(self.eat().value));
-            },
-            TokenType::Whitespace => {
+        if self.at().r#type != TokenType::OpenParen {
+            name = Some(self.expect(TokenType::Identifier, "Expected function name after fn keyword".to_string())?.value);
+        }
+
+        let params = self.parse_params()?;
+        let return_type = self.parse_type_annotation()?;
+        let body = self.parse_function_body(is_generator, false)?;
+
+        return Ok(Box::new(ast::FunctionExpression::new(params, return_type, name, body, false, is_generator)));
+    }
+
+    fn parse_generator_marker(&mut self) -> bool {
+        if self.at().r#type == TokenType::BinaryOperator && self.at().value == "*" {
+            self.eat();
+            return true;
+        }
+        return false;
+    }
+
+    fn parse_arrow_function(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let params: Vec<ast::Parameter>;
+        if self.at().r#type == TokenType::Identifier {
+            params = vec![ast::Parameter::new(ast::Pattern::Identifier(self.eat().value), None, None, false)];
+        } else {
+            params = self.parse_params()?;
+        }
+
+        self.expect(TokenType::Arrow, "Expected => after arrow function parameters".to_string())?;
+
+        let body: Vec<Box<dyn ast::Statement>>;
+        if self.at().r#type == TokenType::LSquirly {
+            body = self.parse_function_body(false, self.is_derived_class)?;
+        } else {
+            let was_generator = self.is_generator;
+            self.is_generator = false;
+
+            let value = self.parse_expression()?;
+
+            self.is_generator = was_generator;
+            body = vec![Box::new(ast::ReturnStatement::new(value))];
+        }
+
+        return Ok(Box::new(ast::FunctionExpression::new(params, None, None, body, true, false)));
+    }
+
+    fn is_arrow_function(&self) -> bool {
+        let mut depth = 0;
+        let mut brackets = 0;
+        let mut has_separator = false;
+
+        for (i, token) in self.tokens.iter().enumerate() {
+            match token.r#type {
+                TokenType::OpenParen => depth += 1,
+                TokenType::CloseParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return self.tokens[i + 1].r#type == TokenType::Arrow;
+                    }
+                },
+                TokenType::OpenBracket | TokenType::LSquirly => brackets += 1,
+                TokenType::CloseBracket | TokenType::RSquirly => brackets -= 1,
+                TokenType::Comma | TokenType::Ellipsis if depth == 1 && brackets == 0 => has_separator = true,
+                TokenType::EndOfFile => return has_separator,
+                _ => {},
+            }
+        }
+
+        return false;
+    }
+
+    fn parse_params(&mut self) -> Result<Vec<ast::Parameter>, ParseError> {
+        self.expect(TokenType::OpenParen, "Expected open parenthesis".to_string())?;
+
+        let mut params: Vec<ast::Parameter> = vec![];
+        let mut names: Vec<String> = vec![];
+        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::CloseParen {
+            let param = self.parse_parameter()?;
+
+            for name in param.pattern.bound_names() {
+                if names.contains(&name) {
+                    return Err(self.error(format!("Duplicate parameter name '{}'", name)));
+                }
+                names.push(name);
+            }
+
+            if param.is_rest && self.is_not_eof() && self.at().r#type != TokenType::CloseParen {
+                return Err(self.error(String::from("Rest parameter must be the last parameter")));
+            }
+
+            params.push(param);
+
+            if self.at().r#type != TokenType::CloseParen {
+                self.expect(TokenType::Comma, "Expected , between parameters".to_string())?;
+            }
+        }
+
+        self.expect(TokenType::CloseParen, "Expected close parenthesis".to_string())?;
+        return Ok(params);
+    }
+
+    fn parse_parameter(&mut self) -> Result<ast::Parameter, ParseError> {
+        if self.at().r#type == TokenType::Ellipsis {
+            self.eat();
+            let pattern = self.parse_binding_pattern()?;
+            let type_annotation = self.parse_type_annotation()?;
+
+            if self.at().r#type == TokenType::Equals {
+                return Err(self.error(String::from("Rest parameter cannot have a default value")));
+            }
+
+            return Ok(ast::Parameter::new(pattern, type_annotation, None, true));
+        }
+
+        let pattern = self.parse_binding_pattern()?;
+        let type_annotation = self.parse_type_annotation()?;
+
+        if self.at().r#type == TokenType::Equals {
+            self.eat();
+            return Ok(ast::Parameter::new(pattern, type_annotation, Some(self.parse_expression()?), false));
+        }
+
+        return Ok(ast::Parameter::new(pattern, type_annotation, None, false));
+    }
+
+    fn parse_binding_pattern(&mut self) -> Result<ast::Pattern, ParseError> {
+        match self.at().r#type {
+            TokenType::Identifier => return Ok(ast::Pattern::Identifier(self.eat().value)),
+            TokenType::OpenBracket => return self.parse_array_pattern(),
+            TokenType::LSquirly => return self.parse_object_pattern(),
+            _ => {
+                return Err(self.unexpected(String::from("Expected identifier or destructuring pattern")));
+            }
+        }
+    }
+
+    fn parse_binding_element(&mut self) -> Result<ast::Pattern, ParseError> {
+        if self.at().r#type == TokenType::Ellipsis {
+            self.eat();
+            return Ok(ast::Pattern::Rest(Box::new(self.nested(Self::parse_binding_pattern)?)));
+        }
+
+        let target = self.parse_binding_pattern()?;
+
+        if self.at().r#type == TokenType::Equals {
+            self.eat();
+            return Ok(ast::Pattern::Default(Box::new(target), self.parse_expression()?));
+        }
+
+        return Ok(target);
+    }
+
+    fn parse_array_pattern(&mut self) -> Result<ast::Pattern, ParseError> {
+        self.eat();
+        let mut elements: Vec<ast::Pattern> = Vec::new();
+
+        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::CloseBracket {
+            let element = self.nested(Self::parse_binding_element)?;
+            let is_rest = matches!(element, ast::Pattern::Rest(_));
+            elements.push(element);
+
+            if is_rest && self.at().r#type != TokenType::CloseBracket {
+                return Err(self.error(String::from("Rest element must be last in an array pattern")));
+            }
+
+            if self.at().r#type != TokenType::CloseBracket {
+                self.expect(TokenType::Comma, "Expected , between array pattern elements".to_string())?;
+            }
+        }
+
+        self.expect(TokenType::CloseBracket, "Expected ] after array pattern".to_string())?;
+        return Ok(ast::Pattern::Array(elements));
+    }
+
+    fn parse_object_pattern(&mut self) -> Result<ast::Pattern, ParseError> {
+        self.eat();
+        let mut properties: Vec<ast::PatternProperty> = Vec::new();
+        let mut rest: Option<Box<ast::Pattern>> = None;
+
+        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+            if self.at().r#type == TokenType::Ellipsis {
                 self.eat();
-                return self.parse_primary_expression();
-            },
-            TokenType::OpenParen => {
+                let name = self.expect(TokenType::Identifier, "Expected identifier after ... in object pattern".to_string())?.value;
+                rest = Some(Box::new(ast::Pattern::Identifier(name)));
+
+                if self.at().r#type != TokenType::RSquirly {
+                    return Err(self.error(String::from("Rest element must be last in an object pattern")));
+                }
+                break;
+            }
+
+            let key = self.expect(TokenType::Identifier, "Expected property name in object pattern".to_string())?.value;
+            let mut value = ast::Pattern::Identifier(key.clone());
+            let mut shorthand = true;
+
+            if self.at().r#type == TokenType::Colon {
                 self.eat();
-                let expression = self.parse_expression();
-                self.expect(TokenType::CloseParen, "Expected closing parenthesis after expression".to_string());
-                return expression;
-            },
-            TokenType::UnaryOperator => {
-                let operator = self.eat().value;
-                let expression = self.parse_primary_expression();
-                return Box::new(ast::UnaryExpression::new(expression, operator));
+                value = self.nested(Self::parse_binding_pattern)?;
+                shorthand = false;
+            }
+
+            if self.at().r#type == TokenType::Equals {
+                self.eat();
+                value = ast::Pattern::Default(Box::new(value), self.parse_expression()?);
+            }
+
+            properties.push(ast::PatternProperty { key, value, shorthand });
+
+            if self.at().r#type != TokenType::RSquirly {
+                self.expect(TokenType::Comma, "Expected , between object pattern properties".to_string())?;
+            }
+        }
+
+        self.expect(TokenType::RSquirly, "Expected } after object pattern".to_string())?;
+        return Ok(ast::Pattern::Object { properties, rest });
+    }
+
+    fn parse_function_body(&mut self, is_generator: bool, is_derived_class: bool) -> Result<Vec<Box<dyn ast::Statement>>, ParseError> {
+        let was_function = self.is_function;
+        let was_loop = self.is_loop;
+        let was_derived_class = self.is_derived_class;
+        let was_generator = self.is_generator;
+        let was_match_guard = self.is_match_guard;
+        let labels = std::mem::take(&mut self.labels);
+        self.is_function = true;
+        self.is_loop = false;
+        self.is_derived_class = is_derived_class;
+        self.is_generator = is_generator;
+        self.is_match_guard = false;
+
+        let block = self.parse_block()?;
+
+        self.is_function = was_function;
+        self.is_loop = was_loop;
+        self.is_derived_class = was_derived_class;
+        self.is_generator = was_generator;
+        self.is_match_guard = was_match_guard;
+        self.labels = labels;
+        return Ok(block.body);
+    }
+
+    fn parse_block(&mut self) -> Result<ast::BlockStatement, ParseError> {
+        self.expect(TokenType::LSquirly, "Expected {".to_string())?;
+
+        let mut body: Vec<Box<dyn ast::Statement>> = Vec::new();
+        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+            body.push(self.nested(Self::parse_statement)?);
+        }
+
+        self.expect(TokenType::RSquirly, "Expected }".to_string())?;
+        return Ok(ast::BlockStatement::new(body));
+    }
+
+    fn parse_args(&mut self) -> Result<Vec<Box<dyn ast::Expression>>, ParseError> {
+        self.expect(TokenType::OpenParen, "Expected open parenthesis".to_string())?;
+
+        let args: Vec<Box<dyn ast::Expression>>;
+        if self.at().r#type == TokenType::CloseParen {
+            args = Vec::new();
+        } else {
+            args = self.parse_args_list()?;
+        }
+
+        self.expect(TokenType::CloseParen, "Expected close parenthesis".to_string())?;
+        return Ok(args);
+    }
+
+    fn parse_args_list(&mut self) -> Result<Vec<Box<dyn ast::Expression>>, ParseError> {
+        let mut args = vec![self.nested(Self::parse_assignment_expression)?];
+
+        while self.at().r#type == TokenType::Comma {
+            self.eat();
+            args.push(self.nested(Self::parse_assignment_expression)?);
+        }
+
+        return Ok(args);
+    }
+
+    fn parse_assignment_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        if self.at().r#type == TokenType::Yield {
+            return self.parse_yield_expression();
+        }
+
+        let left = self.parse_conditional_expression()?;
+
+        if self.at().r#type == TokenType::Equals || self.at().r#type == TokenType::CompoundAssignment {
+            let operator = self.eat().value;
+            let assignee = match left.into_pattern() {
+                Some(ast::Pattern::Default(_, _)) | None => {
+                    return Err(self.error(String::from("Invalid assignment target")));
+                },
+                Some(pattern) => pattern,
+            };
+
+            if operator != "=" {
+                if let ast::Pattern::Array(_) | ast::Pattern::Object { .. } | ast::Pattern::Rest(_) = assignee {
+                    return Err(self.error(format!("Invalid target for {} assignment", operator)));
+                }
+            }
+
+            let value = self.nested(Self::parse_assignment_expression)?;
+
+            return Ok(Box::new(ast::AssignmentExpression::new(assignee, value, operator)));
+        }
+
+        return Ok(left);
+    }
+
+    fn parse_yield_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        if !self.is_generator {
+            return Err(self.error(String::from("Yield expression must be inside a generator function")));
+        }
+        self.eat();
+
+        let delegate = self.parse_generator_marker();
+
+        match self.at().r#type {
+            TokenType::SemiColon | TokenType::CloseParen | TokenType::CloseBracket | TokenType::RSquirly | TokenType::Comma | TokenType::EndOfFile => {
+                if delegate {
+                    return Err(self.unexpected(String::from("Expected expression after yield*")));
+                }
+                return Ok(Box::new(ast::YieldExpression::new(None, false)));
             },
             _ => {
-                eprintln!("Unexpected token: {:?}", token);
-                exit(0);
+                let argument = self.nested(Self::parse_assignment_expression)?;
+                return Ok(Box::new(ast::YieldExpression::new(Some(argument), delegate)));
+            },
+        }
+    }
+
+    fn parse_conditional_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let test = self.parse_nullish_expression()?;
+
+        if self.at().r#type != TokenType::Question {
+            return Ok(test);
+        }
+
+        self.eat();
+        let consequent = self.nested(Self::parse_assignment_expression)?;
+        self.expect(TokenType::Colon, "Expected : in conditional expression".to_string())?;
+        let alternate = self.nested(Self::parse_assignment_expression)?;
+
+        return Ok(Box::new(ast::ConditionalExpression::new(test, consequent, alternate)));
+    }
+
+    fn parse_nullish_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_or_expression()?;
+
+        while self.at().r#type == TokenType::NullishCoalescing {
+            self.eat();
+            let right = self.parse_xor_expression()?;
+
+            if self.at().r#type == TokenType::And || self.at().r#type == TokenType::Or {
+                return Err(self.mixed_nullish_error());
             }
+
+            left = Box::new(ast::LogicalExpression::new(left, right, "??".to_string()));
         }
+
+        return Ok(left);
     }
 
-    fn parse_expression(&mut self) -> Box<dyn ast::Expression> {
-        return self.parse_assignment_expression();
+    fn parse_or_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_and_expression()?;
+        let mut has_operator = false;
+
+        while self.at().r#type == TokenType::Or {
+            self.eat();
+            let right = self.parse_and_expression()?;
+            has_operator = true;
+
+            left = Box::new(ast::LogicalExpression::new(left, right, "or".to_string()));
+        }
+
+        if has_operator && self.at().r#type == TokenType::NullishCoalescing {
+            return Err(self.mixed_nullish_error());
+        }
+
+        return Ok(left);
     }
 
-    fn at(&self) -> &Token {
-        return &self.tokens[0];
+    fn parse_and_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_xor_expression()?;
+        let mut has_operator = false;
+
+        while self.at().r#type == TokenType::And {
+            self.eat();
+            let right = self.parse_xor_expression()?;
+            has_operator = true;
+
+            left = Box::new(ast::LogicalExpression::new(left, right, "and".to_string()));
+        }
+
+        if has_operator && self.at().r#type == TokenType::NullishCoalescing {
+            return Err(self.mixed_nullish_error());
+        }
+
+        return Ok(left);
     }
 
-    fn eat(&mut self) -> Token {
-        return self.tokens.remove(0);
+    fn mixed_nullish_error(&self) -> ParseError {
+        return self.error(String::from("Cannot mix ?? with and/or without parentheses"));
     }
 
-    fn peek(&self) -> &Token {
-        return &self.tokens[1];
+    fn parse_xor_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_not_expression()?;
+
+        while self.at().r#type == TokenType::Xor {
+            self.eat();
+            let right = self.parse_not_expression()?;
+
+            left = Box::new(ast::LogicalExpression::new(left, right, "xor".to_string()));
+        }
+
+        return Ok(left);
     }
 
-    fn expect(&mut self, token: TokenType, message: String) -> Token {
-        if self.at().r#type != token {
-            eprintln!("{}", message);
-            exit(0);
+    fn parse_not_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        if self.at().r#type == TokenType::Not {
+            self.eat();
+            let expression = self.nested(Self::parse_not_expression)?;
+
+            return Ok(Box::new(ast::LogicalExpression{right: expression, operator: "not".to_string(), left: Box::new(ast::NullLiteral::new())}));
         }
-        return self.eat();
+
+        return self.parse_comparison_expression();
     }
 
-    fn is_not_eof(&self) -> bool {
-        return self.tokens[0].r#type != TokenType::EndOfFile;
+    fn parse_comparison_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_pipeline_expression()?;
+
+        while self.at().value == ">" || self.at().value == "<" || (self.at().value == "=" && self.peek().value == "=") || self.at().value == "!=" {
+            let mut operator = self.eat().value;
+            if self.at().value == "=" {
+                operator += self.eat().value.as_str();
+            }
+
+            let right = self.parse_pipeline_expression()?;
+
+            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+        }
+
+        return Ok(left);
+    }
+
+    fn parse_pipeline_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_range_expression()?;
+
+        while self.at().r#type == TokenType::Pipeline {
+            self.eat();
+            let callee = self.parse_member_expression()?;
+
+            let mut arguments: Vec<Box<dyn ast::Expression>> = Vec::new();
+            if self.at().r#type == TokenType::OpenParen {
+                arguments = self.parse_args()?;
+            }
+
+            left = Box::new(ast::PipelineExpression::new(left, callee, arguments));
+        }
+
+        return Ok(left);
+    }
+
+    fn parse_range_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let start = self.parse_object_expression()?;
+
+        if self.at().r#type != TokenType::DotDot && self.at().r#type != TokenType::DotDotEquals {
+            return Ok(start);
+        }
+
+        return self.parse_range_rest(Some(start));
+    }
+
+    fn parse_range_rest(&mut self, start: Option<Box<dyn ast::Expression>>) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let inclusive = self.eat().r#type == TokenType::DotDotEquals;
+
+        let mut end: Option<Box<dyn ast::Expression>> = None;
+        if self.at().r#type != TokenType::CloseBracket {
+            end = Some(self.parse_object_expression()?);
+        } else if inclusive {
+            return Err(self.unexpected(String::from("Inclusive range must have an end")));
+        }
+
+        let mut step: Option<Box<dyn ast::Expression>> = None;
+        if self.at().r#type == TokenType::Identifier && self.at().value == "step" {
+            self.eat();
+            step = Some(self.parse_object_expression()?);
+        }
+
+        return Ok(Box::new(ast::RangeExpression::new(start, end, inclusive, step)));
+    }
+
+    fn parse_object_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        if self.at().r#type != TokenType::LSquirly {
+            return self.parse_array_expression();
+        }
+
+        self.eat();
+        let mut properties: Vec<ast::Property> = Vec::new();
+
+        while self.is_not_eof() && self.at().r#type != TokenType::RSquirly {
+            properties.push(self.parse_object_property()?);
+
+            if self.at().r#type != TokenType::RSquirly {
+                self.expect(TokenType::Comma, "Expected , after object property".to_string())?;
+            }
+        }
+
+        self.expect(TokenType::RSquirly, "Object literal must end with a }".to_string())?;
+        return Ok(Box::new(ast::ObjectLiteral::new(properties)));
+    }
+
+    fn parse_object_property(&mut self) -> Result<ast::Property, ParseError> {
+        if self.at().r#type == TokenType::Ellipsis {
+            self.eat();
+            let argument = self.parse_expression()?;
+            return Ok(ast::Property::new(None, argument, ast::PropertyKind::Spread));
+        }
+
+        let token = self.eat();
+        let key = match token.r#type {
+            TokenType::Identifier => ast::PropertyKey::Identifier(token.value),
+            TokenType::String => ast::PropertyKey::String(token.value),
+            TokenType::Number => ast::PropertyKey::Numeric(token.value.parse::<f64>().unwrap()),
+            TokenType::OpenBracket => {
+                let expression = self.parse_expression()?;
+                self.expect(TokenType::CloseBracket, "Expected ] after computed object key".to_string())?;
+                ast::PropertyKey::Computed(expression)
+            },
+            _ => {
+                return Err(self.unexpected(String::from("Expected identifier, string, number or [ as object key")));
+            }
+        };
+
+        if let ast::PropertyKey::Identifier(name) = &key {
+            if self.at().r#type == TokenType::Comma || self.at().r#type == TokenType::RSquirly {
+                let value = Box::new(ast::Identifier::new(name.clone()));
+                return Ok(ast::Property::new(Some(key), value, ast::PropertyKind::Shorthand));
+            }
+        }
+
+        if self.at().r#type == TokenType::OpenParen {
+            let params = self.parse_params()?;
+            let return_type = self.parse_type_annotation()?;
+            let body = self.parse_function_body(false, false)?;
+            let value = Box::new(ast::FunctionExpression::new(params, return_type, None, body, false, false));
+            return Ok(ast::Property::new(Some(key), value, ast::PropertyKind::Method));
+        }
+
+        self.expect(TokenType::Colon, "Expected : after object key".to_string())?;
+        let value = self.parse_expression()?;
+        return Ok(ast::Property::new(Some(key), value, ast::PropertyKind::Init));
+    }
+
+    fn parse_array_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        if self.at().r#type != TokenType::OpenBracket {
+            return self.parse_bitwise();
+        }
+        let open_bracket = self.eat();
+        let mut elements: Vec<Box<dyn ast::Expression>> = Vec::new();
+        while self.at().r#type != TokenType::CloseBracket {
+            if self.at().r#type == TokenType::EndOfFile {
+                return Err(ParseError::UnexpectedEof {
+                    message: String::from("Unclosed ["),
+                    line: open_bracket.line,
+                    column: open_bracket.column,
+                });
+            }
+
+            if self.at().r#type == TokenType::Ellipsis {
+                self.eat();
+                elements.push(Box::new(ast::SpreadElement::new(self.parse_expression()?)));
+            } else {
+                elements.push(self.parse_expression()?);
+            }
+
+            if self.at().r#type == TokenType::Comma {
+                self.eat();
+            } else if self.at().r#type != TokenType::CloseBracket && self.at().r#type != TokenType::EndOfFile {
+                return Err(self.unexpected(String::from("Expected , or ] after array element")));
+            }
+        }
+        self.expect(TokenType::CloseBracket, "Expected closing bracket after array expression".to_string())?;
+        return Ok(Box::new(ast::ArrayLiteral::new(elements)));
+    }
+
+    fn parse_bitwise(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_bitwise_shift_bit()?;
+
+        while self.at().value == "&" || self.at().value == "|" || self.at().value == "^" {
+            let operator = self.eat().value;
+
+            let right = self.parse_bitwise_shift_bit()?;
+
+            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+        }
+
+        return Ok(left)
+    }
+
+    fn parse_bitwise_shift_bit(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_additive_expression()?;
+
+        while self.at().value == "<<" || self.at().value == ">>" || self.at().value == ">>>" {
+            let operator = self.eat().value;
+
+            let right = self.parse_additive_expression()?;
+
+            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+        }
+
+        return Ok(left)
+    }
+
+    fn parse_additive_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_multiplicative_expression()?;
+
+        while self.at().value == "+" || self.at().value == "-" {
+            let operator = self.eat().value;
+
+            let right = self.parse_multiplicative_expression()?;
+
+            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+        }
+
+        return Ok(left)
+    }
+
+    fn parse_multiplicative_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut left = self.parse_update_expression()?;
+
+        while self.at().value == "*" || self.at().value == "/" || self.at().value == "%" || self.at().value == "**" || self.at().value == "//" {
+            let operator = self.eat().value;
+            
+            let right = self.parse_update_expression()?;
+
+            left = Box::new(ast::BinaryExpression::new(left, right, operator));
+        }
+
+        return Ok(left)
+    }
+
+    fn parse_update_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let argument = self.parse_call_member_expression()?;
+
+        if self.at().value == "++" || self.at().value == "--" {
+            let operator = self.eat().value;
+            self.check_update_target(argument.as_ref(), &operator)?;
+            return Ok(Box::new(ast::UpdateExpression::new(false, operator, argument)));
+        }
+
+        return Ok(argument);
+    }
+
+    fn check_update_target(&self, argument: &dyn ast::Expression, operator: &str) -> Result<(), ParseError> {
+        match argument.kind() {
+            ast::NodeType::IdentifierType => {},
+            ast::NodeType::MemberExpressionType if !argument.is_optional_chain() => {},
+            _ => {
+                return Err(self.error(format!("Invalid target for {} operator", operator)));
+            }
+        }
+        return Ok(());
+    }
+
+    fn parse_call_member_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut expression = self.parse_member_expression()?;
+
+        loop {
+            if self.at().r#type == TokenType::OpenParen {
+                expression = Box::new(ast::CallExpression::new(expression, self.parse_args()?, false));
+            } else if self.at().r#type == TokenType::QuestionDot && self.peek().r#type == TokenType::OpenParen {
+                self.eat();
+                expression = Box::new(ast::CallExpression::new(expression, self.parse_args()?, true));
+            } else if self.is_member_access() {
+                expression = self.parse_member_access(expression)?;
+            } else {
+                break;
+            }
+        }
+
+        return Ok(expression);
+    }
+
+    fn parse_member_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut object = self.parse_primary_expression()?;
+
+        while self.is_member_access() {
+            object = self.parse_member_access(object)?;
+        }
+
+        return Ok(object);
+    }
+
+    fn is_member_access(&self) -> bool {
+        match self.at().r#type {
+            TokenType::Dot | TokenType::OpenBracket => return true,
+            TokenType::QuestionDot => return self.peek().r#type != TokenType::OpenParen,
+            _ => return false,
+        }
+    }
+
+    fn parse_member_access(&mut self, object: Box<dyn ast::Expression>) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut optional = false;
+        if self.at().r#type == TokenType::QuestionDot {
+            self.eat();
+            optional = true;
+        }
+
+        let property: Box<dyn ast::Expression>;
+        let computed: bool;
+
+        if self.at().r#type == TokenType::OpenBracket {
+            self.eat();
+            computed = true;
+
+            if self.at().r#type == TokenType::DotDot || self.at().r#type == TokenType::DotDotEquals {
+                property = self.parse_range_rest(None)?;
+            } else {
+                property = self.parse_expression()?;
+            }
+
+            self.expect(TokenType::CloseBracket, "Expected closing bracket after computed property".to_string())?;
+        } else {
+            if !optional {
+                self.expect(TokenType::Dot, "Expected . or [ after object".to_string())?;
+            }
+
+            computed = false;
+            property = self.parse_primary_expression()?;
+
+            match property.kind() {
+                ast::NodeType::IdentifierType => {},
+                _ => {
+                    return Err(self.unexpected(String::from("Expected identifier .")));
+                }
+            }
+        }
+
+        return Ok(Box::new(ast::MemberExpression::new(object, property, computed, optional)));
+    }
+
+    fn parse_primary_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let token = self.at().r#type;
+
+        match token {
+            TokenType::Identifier => {
+                if self.peek().r#type == TokenType::Arrow && !self.is_match_guard {
+                    return self.parse_arrow_function();
+                }
+
+                if self.peek().r#type == TokenType::ColonColon {
+                    return self.parse_path_expression();
+                }
+
+                return Ok(Box::new(ast::Identifier::new(self.eat().value)));
+            },
+            TokenType::Function => return self.parse_function_expression(),
+            TokenType::Match => return self.parse_match_expression(),
+            TokenType::This => {
+                self.eat();
+                return Ok(Box::new(ast::ThisExpression::new()));
+            },
+            TokenType::Super => {
+                if !self.is_derived_class {
+                    return Err(self.error(String::from("'super' must be used inside a class that extends another class")));
+                }
+
+                self.eat();
+                return Ok(Box::new(ast::SuperExpression::new()));
+            },
+            TokenType::Number => {
+                return Ok(Box::new(ast::NumericLiteral::new(self.eat().value.parse::<f64>().unwrap())));
+            },
+            TokenType::String => {
+                return Ok(Box::new(ast::StringLiteral::new(self.eat().value)));
+            },
+            TokenType::Whitespace => {
+                self.eat();
+                return self.nested(Self::parse_primary_expression);
+            },
+            TokenType::OpenParen => {
+                if !self.is_match_guard && self.is_arrow_function() {
+                    return self.parse_arrow_function();
+                }
+
+                self.eat();
+                let expression = self.parse_expression()?;
+                self.expect(TokenType::CloseParen, "Expected closing parenthesis after expression".to_string())?;
+                return Ok(expression);
+            },
+            TokenType::UnaryOperator => {
+                let operator = self.eat().value;
+
+                if operator == "++" || operator == "--" {
+                    let argument = self.nested(Self::parse_call_member_expression)?;
+                    self.check_update_target(argument.as_ref(), &operator)?;
+                    return Ok(Box::new(ast::UpdateExpression::new(true, operator, argument)));
+                }
+
+                let expression = self.nested(Self::parse_primary_expression)?;
+                return Ok(Box::new(ast::UnaryExpression::new(expression, operator)));
+            },
+            TokenType::EndOfFile => {
+                return Err(self.unexpected(String::from("Expected expression")));
+            },
+            _ => {
+                return Err(self.unexpected(format!("Unexpected token: {:?}", token)));
+            }
+        }
+    }
+
+    fn parse_path_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        let mut segments = vec![self.eat().value];
+
+        while self.at().r#type == TokenType::ColonColon {
+            self.eat();
+            segments.push(self.expect(TokenType::Identifier, "Expected name after ::".to_string())?.value);
+        }
+
+        return Ok(Box::new(ast::PathExpression::new(segments)));
+    }
+
+    fn parse_match_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        self.eat();
+        let was_match_guard = self.is_match_guard;
+        self.is_match_guard = false;
+
+        let subject = self.parse_expression()?;
+        self.expect(TokenType::LSquirly, "Expected { after match subject".to_string())?;
+
+        let mut arms: Vec<ast::MatchArm> = Vec::new();
+        while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+            let pattern = self.parse_match_pattern()?;
+
+            let mut guard: Option<Box<dyn ast::Expression>> = None;
+            if self.at().r#type == TokenType::If {
+                self.eat();
+                self.is_match_guard = true;
+                guard = Some(self.parse_expression()?);
+                self.is_match_guard = false;
+            }
+
+            self.expect(TokenType::Arrow, "Expected => after match pattern".to_string())?;
+
+            let body: ast::MatchArmBody;
+            if self.at().r#type == TokenType::LSquirly {
+                body = ast::MatchArmBody::Block(self.parse_block()?);
+
+                if self.at().r#type == TokenType::Comma {
+                    self.eat();
+                }
+            } else {
+                body = ast::MatchArmBody::Expression(self.parse_expression()?);
+
+                if self.at().r#type != TokenType::RSquirly {
+                    self.expect(TokenType::Comma, "Expected , after match arm".to_string())?;
+                }
+            }
+
+            arms.push(ast::MatchArm::new(pattern, guard, body));
+        }
+
+        self.expect(TokenType::RSquirly, "Expected } after match arms".to_string())?;
+        self.is_match_guard = was_match_guard;
+        return Ok(Box::new(ast::MatchExpression::new(subject, arms)));
+    }
+
+    fn parse_match_pattern(&mut self) -> Result<ast::MatchPattern, ParseError> {
+        match self.at().r#type {
+            TokenType::Identifier => {
+                let name = self.eat().value;
+                if name == "_" {
+                    return Ok(ast::MatchPattern::Wildcard);
+                }
+
+                if name == "null" {
+                    return Ok(ast::MatchPattern::Literal(Box::new(ast::NullLiteral::new())));
+                }
+
+                if name == "true" || name == "false" {
+                    return Ok(ast::MatchPattern::Literal(Box::new(ast::Identifier::new(name))));
+                }
+
+                if self.at().r#type != TokenType::ColonColon && self.at().r#type != TokenType::OpenParen {
+                    return Ok(ast::MatchPattern::Binding(name));
+                }
+
+                let mut path = vec![name];
+                while self.at().r#type == TokenType::ColonColon {
+                    self.eat();
+                    path.push(self.expect(TokenType::Identifier, "Expected name after ::".to_string())?.value);
+                }
+
+                let mut fields: Option<Vec<ast::MatchPattern>> = None;
+                if self.at().r#type == TokenType::OpenParen {
+                    self.eat();
+                    let mut patterns: Vec<ast::MatchPattern> = Vec::new();
+                    while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::CloseParen {
+                        patterns.push(self.nested(Self::parse_match_pattern)?);
+
+                        if self.at().r#type != TokenType::CloseParen {
+                            self.expect(TokenType::Comma, "Expected , between variant patterns".to_string())?;
+                        }
+                    }
+                    self.expect(TokenType::CloseParen, "Expected ) after variant patterns".to_string())?;
+                    fields = Some(patterns);
+                }
+
+                return Ok(ast::MatchPattern::Variant { path, fields });
+            },
+            TokenType::Number | TokenType::String => {
+                return Ok(ast::MatchPattern::Literal(self.parse_primary_expression()?));
+            },
+            TokenType::UnaryOperator if self.at().value == "-" && self.peek().r#type == TokenType::Number => {
+                return Ok(ast::MatchPattern::Literal(self.parse_primary_expression()?));
+            },
+            TokenType::OpenBracket => {
+                self.eat();
+                let mut elements: Vec<ast::MatchPattern> = Vec::new();
+
+                while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::CloseBracket {
+                    if self.at().r#type == TokenType::Ellipsis {
+                        self.eat();
+                        let name = self.expect(TokenType::Identifier, "Expected identifier after ... in match pattern".to_string())?.value;
+                        elements.push(ast::MatchPattern::Rest(name));
+
+                        if self.at().r#type != TokenType::CloseBracket {
+                            return Err(self.error(String::from("Rest element must be last in an array pattern")));
+                        }
+                        break;
+                    }
+
+                    elements.push(self.nested(Self::parse_match_pattern)?);
+
+                    if self.at().r#type != TokenType::CloseBracket {
+                        self.expect(TokenType::Comma, "Expected , between array pattern elements".to_string())?;
+                    }
+                }
+
+                self.expect(TokenType::CloseBracket, "Expected ] after array pattern".to_string())?;
+                return Ok(ast::MatchPattern::Array(elements));
+            },
+            TokenType::LSquirly => {
+                self.eat();
+                let mut properties: Vec<(String, ast::MatchPattern)> = Vec::new();
+
+                while self.at().r#type != TokenType::EndOfFile && self.at().r#type != TokenType::RSquirly {
+                    let key = self.expect(TokenType::Identifier, "Expected property name in object pattern".to_string())?.value;
+
+                    if self.at().r#type == TokenType::Colon {
+                        self.eat();
+                        properties.push((key, self.nested(Self::parse_match_pattern)?));
+                    } else {
+                        properties.push((key.clone(), ast::MatchPattern::Binding(key)));
+                    }
+
+                    if self.at().r#type != TokenType::RSquirly {
+                        self.expect(TokenType::Comma, "Expected , between object pattern properties".to_string())?;
+                    }
+                }
+
+                self.expect(TokenType::RSquirly, "Expected } after object pattern".to_string())?;
+                return Ok(ast::MatchPattern::Object(properties));
+            },
+            _ => {
+                return Err(self.unexpected(String::from("Expected pattern in match arm")));
+            }
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<Box<dyn ast::Expression>, ParseError> {
+        return self.nested(Self::parse_assignment_expression);
+    }
+
+    fn at(&self) -> &Token {
+        return &self.tokens[0];
+    }
+
+    fn eat(&mut self) -> Token {
+        if self.tokens.len() == 1 {
+            return self.tokens[0].clone();
+        }
+        return self.tokens.pop_front().unwrap();
+    }
+
+    fn peek(&self) -> &Token {
+        if self.tokens.len() == 1 {
+            return &self.tokens[0];
+        }
+        return &self.tokens[1];
+    }
+
+    fn expect(&mut self, token: TokenType, message: String) -> Result<Token, ParseError> {
+        if self.at().r#type != token {
+            return Err(self.unexpected(message));
+        }
+        return Ok(self.eat());
+    }
+
+    fn expect_terminator(&mut self) -> Result<(), ParseError> {
+        if self.at().r#type != TokenType::SemiColon {
+            return Err(self.unexpected(String::from("Expected ;")));
+        }
+        self.eat();
+        return Ok(());
+    }
+
+    fn expect_end(&self) -> Result<(), ParseError> {
+        if self.is_not_eof() {
+            return Err(ParseError::TrailingInput {
+                found: self.at().value.clone(),
+                line: self.at().line,
+                column: self.at().column,
+            });
+        }
+        return Ok(());
+    }
+
+    fn expect_contextual(&mut self, keyword: &str, message: String) -> Result<Token, ParseError> {
+        if self.at().r#type != TokenType::Identifier || self.at().value != keyword {
+            return Err(self.unexpected(message));
+        }
+        return Ok(self.eat());
+    }
+
+    fn nested<T>(&mut self, parse: fn(&mut Parser) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        self.enter_nesting()?;
+        let result = parse(self);
+        self.depth -= 1;
+        return result;
+    }
+
+    fn enter_nesting(&mut self) -> Result<(), ParseError> {
+        self.depth += 1;
+        if self.depth > self.max_depth {
+            return Err(ParseError::TooDeep {
+                line: self.at().line,
+                column: self.at().column,
+            });
+        }
+        return Ok(());
+    }
+
+    fn unexpected(&self, message: String) -> ParseError {
+        if !self.is_not_eof() {
+            return ParseError::UnexpectedEof {
+                message,
+                line: self.at().line,
+                column: self.at().column,
+            };
+        }
+
+        return self.error(message);
+    }
+
+    fn error(&self, message: String) -> ParseError {
+        return ParseError::Syntax {
+            message,
+            line: self.at().line,
+            column: self.at().column,
+        };
+    }
+
+    fn is_not_eof(&self) -> bool {
+        return self.tokens[0].r#type != TokenType::EndOfFile;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Completeness, Parser};
+    use crate::ast::{self, Statement};
+
+    fn parse(source_code: &str) -> String {
+        return Parser::new().produce_ast(source_code).unwrap().to_string();
+    }
+
+    #[test]
+    fn labeled_break_and_continue_target_enclosing_loops() {
+        assert_eq!(parse("'outer: loop { continue 'outer; }"), "'outer: loop {\ncontinue 'outer;\n}\n");
+        assert_eq!(parse("'a: foreach x in xs { break 'a; }"), "'a: for (x in xs) {\nbreak 'a;\n}\n");
+        assert!(Parser::new().produce_ast("'outer: while a { while b { break 'outer; } }").is_ok());
+    }
+
+    #[test]
+    fn undefined_labels_are_rejected() {
+        let error = Parser::new().produce_ast("while a { break 'missing; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Undefined label 'missing' at line 1, column 25");
+
+        let error = Parser::new().produce_ast("while a { continue 'missing; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Undefined label 'missing' at line 1, column 28");
+
+        let error = Parser::new().produce_ast("'a: while x { } while y { break 'a; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Undefined label 'a' at line 1, column 35");
+    }
+
+    #[test]
+    fn continue_must_be_inside_a_loop() {
+        let error = Parser::new().produce_ast("continue;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Continue statement must be inside a loop at line 1, column 1");
+    }
+
+    #[test]
+    fn else_if_chains_print_flat() {
+        assert_eq!(parse("if a { let x = 1; } else if c { let y = 2; } else { let z = 3; }"), "if (a) {\nlet x = 1;\n} else if (c) {\nlet y = 2;\n} else {\nlet z = 3;\n}\n");
+        assert_eq!(parse("if a { let x = 1; }\nelse if c { let y = 2; }"), "if (a) {\nlet x = 1;\n} else if (c) {\nlet y = 2;\n}\n");
+    }
+
+    #[test]
+    fn stray_else_is_rejected() {
+        let error = Parser::new().produce_ast("else { b; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected else without a matching if at line 1, column 1");
+
+        let error = Parser::new().produce_ast("if a { b; } c; else { d; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected else without a matching if at line 1, column 16");
+
+        let error = Parser::new().produce_ast("if a { b; } else c;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected { at line 1, column 18");
+    }
+
+    #[test]
+    fn function_expressions_can_be_passed_as_values() {
+        assert_eq!(parse("let f = func(a, b) { return a; };"), "let f = function(a, b) {\nreturn a;\n};\n");
+        assert_eq!(parse("let n = func named(a) { return a; };"), "let n = function named(a) {\nreturn a;\n};\n");
+        assert_eq!(parse("call(func() { });"), "call(function() {\n});\n");
+    }
+
+    #[test]
+    fn arrow_functions_wrap_expression_bodies_in_return() {
+        assert_eq!(parse("xs.map(x => x * 2);"), "xs.map((x) => {\nreturn x * 2;\n});\n");
+        assert_eq!(parse("let h = (a, b) => a + b;"), "let h = (a, b) => {\nreturn a + b;\n};\n");
+        assert_eq!(parse("let g = () => { return 1; };"), "let g = () => {\nreturn 1;\n};\n");
+
+        let error = Parser::new().produce_ast("let m = (a) =>;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected token: SemiColon at line 1, column 15");
+    }
+
+    #[test]
+    fn class_members_fields_methods_and_constructor() {
+        assert_eq!(parse("class A { x = 1; static y; constructor(a) { this.x = a; } func get() { return this.x; } static make() { return 1; } }"), "class A {\nx = 1;\nstatic y;\nconstructor(a) {\nthis.x = a;\n}\nget() {\nreturn this.x;\n}\nstatic make() {\nreturn 1;\n}\n}\n");
+        assert_eq!(parse("class B extends A { constructor() { super(); } run() { return super.get(); } }"), "class B extends A {\nconstructor() {\nsuper();\n}\nrun() {\nreturn super.get();\n}\n}\n");
+    }
+
+    #[test]
+    fn invalid_class_bodies_are_rejected() {
+        let error = Parser::new().produce_ast("class C { constructor() { } constructor() { } }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Class 'C' can only have one constructor at line 1, column 47");
+
+        let error = Parser::new().produce_ast("class E extends { }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected class name after extends keyword at line 1, column 17");
+
+        let error = Parser::new().produce_ast("class F { 1; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected class member name at line 1, column 11");
+    }
+
+    #[test]
+    fn super_requires_a_derived_class() {
+        let error = Parser::new().produce_ast("class D { run() { return super.x; } }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: 'super' must be used inside a class that extends another class at line 1, column 26");
+
+        let error = Parser::new().produce_ast("class B extends A { run() { func f() { return super.x; } } }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: 'super' must be used inside a class that extends another class at line 1, column 47");
+
+        assert!(Parser::new().produce_ast("class B extends A { run() { let f = () => { return super.x; }; let g = () => super.y; } }").is_ok());
+    }
+
+    #[test]
+    fn this_is_allowed_outside_class_bodies() {
+        assert!(Parser::new().produce_ast("func f() { return this.a; }").is_ok());
+        assert!(Parser::new().produce_ast("class A { get() { return this.a; } }").is_ok());
+    }
+
+    #[test]
+    fn import_forms_round_trip() {
+        assert_eq!(parse("import \"path\";"), "import \"path\";\n");
+        assert_eq!(parse("import { a, b as c } from \"path\";"), "import { a, b as c } from \"path\";\n");
+        assert_eq!(parse("import * as m from \"path\";"), "import * as m from \"path\";\n");
+    }
+
+    #[test]
+    fn export_wraps_declarations() {
+        assert_eq!(parse("export let x = 1;"), "export let x = 1;\n");
+        assert_eq!(parse("export const y = 2;"), "export const y = 2;\n");
+        assert_eq!(parse("export class K { }"), "export class K {\n}\n");
+        assert_eq!(parse("export func* g() { yield 1; }"), "export function* g() {\n\tyield 1;\n\n}\n");
+
+        let error = Parser::new().produce_ast("export func* () { yield 1; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Exported functions must have a name at line 1, column 8");
+
+        let error = Parser::new().produce_ast("export x;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected let, const, func, class or enum after export keyword at line 1, column 8");
+    }
+
+    #[test]
+    fn malformed_imports_are_rejected() {
+        let error = Parser::new().produce_ast("import { a from \"p\";").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected , between import specifiers at line 1, column 12");
+
+        let error = Parser::new().produce_ast("import * m from \"p\";").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected as after * in import at line 1, column 10");
+
+        let error = Parser::new().produce_ast("import { a } \"p\";").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected from after import specifiers at line 1, column 14");
+    }
+
+    #[test]
+    fn object_literal_keys_shorthand_spread_and_methods() {
+        assert_eq!(parse("let o = {\"content-type\": x, 1: y, [k]: v};"), "let o = {\"content-type\": x, 1: y, [k]: v};\n");
+        assert_eq!(parse("let o = {a, b: 2};"), "let o = {a, b: 2};\n");
+        assert_eq!(parse("let o = {...base, c: 1,};"), "let o = {...base, c: 1};\n");
+        assert_eq!(parse("let o = { f() { return 1; } };"), "let o = {f() {\nreturn 1;\n}};\n");
+    }
+
+    #[test]
+    fn object_literal_requires_separators() {
+        let error = Parser::new().produce_ast("let o = {a: 1 b: 2};").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected , after object property at line 1, column 15");
+
+        let error = Parser::new().produce_ast("let o = {[k] v};").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected : after object key at line 1, column 14");
+    }
+
+    #[test]
+    fn this_is_allowed_in_object_methods() {
+        assert!(Parser::new().produce_ast("func f() { let o = { g() { return this.a; } }; }").is_ok());
+    }
+
+    #[test]
+    fn array_literals_print_separated_elements() {
+        assert_eq!(parse("let a = [1, 2, 3];"), "let a = [1, 2, 3];\n");
+        assert_eq!(parse("let a = [...xs, 4];"), "let a = [...xs, 4];\n");
+        assert_eq!(parse("let a = [];"), "let a = [];\n");
+    }
+
+    #[test]
+    fn array_literals_allow_one_trailing_comma() {
+        assert_eq!(parse("let a = [1, 2, 3,];"), "let a = [1, 2, 3];\n");
+
+        let error = Parser::new().produce_ast("let a = [1,, 2];").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected token: Comma at line 1, column 12");
+    }
+
+    #[test]
+    fn array_literals_require_separators() {
+        let error = Parser::new().produce_ast("let a = [1 2 3];").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected , or ] after array element at line 1, column 12");
+    }
+
+    #[test]
+    fn unclosed_array_reports_opening_bracket() {
+        let error = Parser::new().produce_ast("let a = [\n1,\n2").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected end of input, Unclosed [ at line 1, column 9");
+    }
+
+    #[test]
+    fn destructuring_in_declarations_and_bindings() {
+        assert_eq!(parse("let [a, b, ...rest] = xs;"), "let [a, b, ...rest] = xs;\n");
+        assert_eq!(parse("const {name, age: years = 0} = person;"), "const {name, age: years = 0} = person;\n");
+        assert_eq!(parse("foreach [k, v] in pairs { }"), "for ([k, v] in pairs) {\n}\n");
+        assert!(Parser::new().produce_ast("func f([a, b], {c}) { return a; }").is_ok());
+    }
+
+    #[test]
+    fn destructuring_assignment_targets() {
+        assert_eq!(parse("[a, b] = [b, a];"), "[a, b] = [b, a];\n");
+        assert_eq!(parse("({a, b} = o);"), "({a, b} = o);\n");
+        assert_eq!(parse(&parse("({a, b} = o);")), "({a, b} = o);\n");
+    }
+
+    #[test]
+    fn invalid_binding_patterns_are_rejected() {
+        let error = Parser::new().produce_ast("let [a, ...rest, b] = xs;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Rest element must be last in an array pattern at line 1, column 16");
+
+        let error = Parser::new().produce_ast("let 5 = x;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected identifier or destructuring pattern at line 1, column 5");
+    }
+
+    #[test]
+    fn default_and_rest_parameters() {
+        assert_eq!(parse("func f(a, b = 10, ...rest) { }"), "function f(a, b = 10, ...rest) {\n\t\n}\n");
+        assert_eq!(parse("let g = (a = 1, ...r) => a;"), "let g = (a = 1, ...r) => {\nreturn a;\n};\n");
+    }
+
+    #[test]
+    fn rest_parameter_must_be_last() {
+        let error = Parser::new().produce_ast("func f(...rest, a) { }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Rest parameter must be the last parameter at line 1, column 15");
+
+        let error = Parser::new().produce_ast("func f(...a, ...b) { }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Rest parameter must be the last parameter at line 1, column 12");
+
+        let error = Parser::new().produce_ast("func f(...rest = 1) { }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Rest parameter cannot have a default value at line 1, column 16");
+    }
+
+    #[test]
+    fn duplicate_parameters_are_rejected() {
+        let error = Parser::new().produce_ast("func f(a, a) { }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Duplicate parameter name 'a' at line 1, column 12");
+
+        let error = Parser::new().produce_ast("func f(a, [b, a]) { }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Duplicate parameter name 'a' at line 1, column 17");
+    }
+
+    #[test]
+    fn conditional_expression_binds_below_or() {
+        assert_eq!(parse("let x = a ? b : c;"), "let x = a ? b : c;\n");
+        assert_eq!(parse("let x = a ? b : c ? d : e;"), "let x = a ? b : c ? d : e;\n");
+        assert_eq!(parse("let x = a or b ? c : d;"), "let x = a or b ? c : d;\n");
+    }
+
+    #[test]
+    fn conditional_expression_requires_both_branches() {
+        let error = Parser::new().produce_ast("let x = a ? b;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected : in conditional expression at line 1, column 14");
+
+        let error = Parser::new().produce_ast("let x = a ? : c;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected token: Colon at line 1, column 13");
+    }
+
+    #[test]
+    fn optional_chaining_members_and_calls() {
+        assert_eq!(parse("let x = a?.b?.[k]?.();"), "let x = a?.b?.[k]?.();\n");
+        assert_eq!(parse("let x = f?.(1).c;"), "let x = f?.(1).c;\n");
+    }
+
+    #[test]
+    fn nullish_coalescing_chains() {
+        assert_eq!(parse("let x = a ?? b ?? c;"), "let x = a ?? b ?? c;\n");
+        assert!(Parser::new().produce_ast("let x = (a ?? b) or c;").is_ok());
+        assert!(Parser::new().produce_ast("let x = a ?? (b or c);").is_ok());
+    }
+
+    #[test]
+    fn nullish_cannot_mix_with_and_or() {
+        let cases = [
+            ("let x = a ?? b or c;", "Error: Cannot mix ?? with and/or without parentheses at line 1, column 16"),
+            ("let x = a or b ?? c;", "Error: Cannot mix ?? with and/or without parentheses at line 1, column 16"),
+            ("let x = a and b ?? c;", "Error: Cannot mix ?? with and/or without parentheses at line 1, column 17"),
+            ("let x = a ?? b and c;", "Error: Cannot mix ?? with and/or without parentheses at line 1, column 16"),
+        ];
+
+        for (source_code, message) in cases {
+            let error = Parser::new().produce_ast(source_code).err().unwrap();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn compound_assignment_operators() {
+        assert_eq!(parse("x += 1; x -= 1; x *= 2; x /= 2; x %= 2; x **= 2; x //= 2;"), "x += 1;\nx -= 1;\nx *= 2;\nx /= 2;\nx %= 2;\nx **= 2;\nx //= 2;\n");
+        assert_eq!(parse("x &= 1; x |= 1; x ^= 1; x <<= 1; x >>= 1;"), "x &= 1;\nx |= 1;\nx ^= 1;\nx <<= 1;\nx >>= 1;\n");
+    }
+
+    #[test]
+    fn prefix_and_postfix_update_expressions() {
+        assert_eq!(parse("i++; i--; ++i; --i; a.b++; a[0]--;"), "i++;\ni--;\n++i;\n--i;\na.b++;\na[0]--;\n");
+    }
+
+    #[test]
+    fn invalid_assignment_targets_are_rejected() {
+        let cases = [
+            ("1 = x;", "Error: Invalid assignment target at line 1, column 5"),
+            ("f() = 1;", "Error: Invalid assignment target at line 1, column 7"),
+            ("a + b = 1;", "Error: Invalid assignment target at line 1, column 9"),
+            ("[a, b] += c;", "Error: Invalid target for += assignment at line 1, column 11"),
+            ("({a} += o);", "Error: Invalid target for += assignment at line 1, column 9"),
+            ("5++;", "Error: Invalid target for ++ operator at line 1, column 4"),
+            ("++f();", "Error: Invalid target for ++ operator at line 1, column 6"),
+            ("(a + b)--;", "Error: Invalid target for -- operator at line 1, column 10"),
+        ];
+
+        for (source_code, message) in cases {
+            let error = Parser::new().produce_ast(source_code).err().unwrap();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn optional_chain_is_not_an_assignment_target() {
+        let cases = [
+            ("a?.b = 1;", "Error: Invalid assignment target at line 1, column 8"),
+            ("a?.b += 1;", "Error: Invalid assignment target at line 1, column 9"),
+            ("a?.b.c = 1;", "Error: Invalid assignment target at line 1, column 10"),
+            ("[a?.b] = c;", "Error: Invalid assignment target at line 1, column 10"),
+            ("a?.b++;", "Error: Invalid target for ++ operator at line 1, column 7"),
+            ("--a?.[0];", "Error: Invalid target for -- operator at line 1, column 9"),
+        ];
+
+        for (source_code, message) in cases {
+            let error = Parser::new().produce_ast(source_code).err().unwrap();
+            assert_eq!(error.to_string(), message);
+        }
+
+        assert_eq!(parse("a.b = c?.d;"), "a.b = c?.d;\n");
+    }
+
+    #[test]
+    fn match_expression_structural_patterns() {
+        assert_eq!(parse("let r = match v { 0 => \"zero\", [a, b] => a, {kind: \"x\", data} => data, n if n > 3 => n, _ => 1 };"), "let r = match v {\n0 => \"zero\",\n[a, b] => a,\n{kind: \"x\", data} => data,\nn if n > 3 => n,\n_ => 1,\n};\n");
+        assert_eq!(parse("let r = match v { [a, ...r] => r, null => 0, true => 1, };"), "let r = match v {\n[a, ...r] => r,\nnull => 0,\ntrue => 1,\n};\n");
+    }
+
+    #[test]
+    fn match_keyword_patterns_are_literals() {
+        for source_code in ["null", "true", "false"] {
+            let mut parser = Parser::new();
+            parser.load(source_code).unwrap();
+            assert!(matches!(parser.parse_match_pattern().unwrap(), ast::MatchPattern::Literal(_)));
+        }
+
+        let mut parser = Parser::new();
+        parser.load("nothing").unwrap();
+        assert!(matches!(parser.parse_match_pattern().unwrap(), ast::MatchPattern::Binding(_)));
+    }
+
+    #[test]
+    fn match_guards_stop_before_the_arm_arrow() {
+        assert_eq!(parse("let r = match v { n if n > m => n, _ => 0 };"), "let r = match v {\nn if n > m => n,\n_ => 0,\n};\n");
+        assert_eq!(parse("let r = match v { [a, b] if a > b => 1, _ => 0 };"), "let r = match v {\n[a, b] if a > b => 1,\n_ => 0,\n};\n");
+        assert_eq!(parse("let r = match v { n if (n > 1) => 1, _ => 0 };"), "let r = match v {\nn if n > 1 => 1,\n_ => 0,\n};\n");
+        assert!(Parser::new().produce_ast("let r = match v { n if all(func(x) { let f = x => x; return f(x); }) => x => n, _ => 0 };").is_ok());
+    }
+
+    #[test]
+    fn match_statement_accepts_block_arms() {
+        assert_eq!(parse("match v { \"a\" => f(), _ => { g(); } }"), "match v {\n\"a\" => f(),\n_ => {\ng();\n},\n};\n");
+    }
+
+    #[test]
+    fn malformed_match_arms_are_rejected() {
+        let error = Parser::new().produce_ast("let r = match v { 0 1 };").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected => after match pattern at line 1, column 21");
+
+        let error = Parser::new().produce_ast("let r = match v { 0 => 1 1 => 2 };").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected , after match arm at line 1, column 26");
+    }
+
+    #[test]
+    fn try_catch_finally_forms() {
+        assert_eq!(parse("try { f(); } catch (e) { g(e); } finally { h(); }"), "try {\nf();\n} catch (e) {\ng(e);\n} finally {\nh();\n}\n");
+        assert_eq!(parse("try { f(); } finally { }"), "try {\nf();\n} finally {\n}\n");
+        assert_eq!(parse("try { f(); } catch { }"), "try {\nf();\n} catch {\n}\n");
+    }
+
+    #[test]
+    fn try_requires_catch_or_finally() {
+        let error = Parser::new().produce_ast("try { f(); } x;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Try statement must have a catch or finally block at line 1, column 14");
+
+        let error = Parser::new().produce_ast("try { f(); }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected end of input, Try statement must have a catch or finally block at line 1, column 13");
+    }
+
+    #[test]
+    fn throw_requires_an_expression() {
+        assert_eq!(parse("throw new_error(\"x\");"), "throw new_error(\"x\");\n");
+
+        let error = Parser::new().produce_ast("throw;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected token: SemiColon at line 1, column 6");
+    }
+
+    #[test]
+    fn ranges_in_foreach() {
+        assert_eq!(parse("foreach i in 0..10 { }"), "for (i in 0..10) {\n}\n");
+        assert_eq!(parse("foreach i in 0..=n step 2 { }"), "for (i in 0..=n step 2) {\n}\n");
+        assert_eq!(parse("let r = a + 1..b * 2;"), "let r = a + 1..b * 2;\n");
+    }
+
+    #[test]
+    fn slice_indexing_with_open_ranges() {
+        assert_eq!(parse("let s = xs[1..3];"), "let s = xs[1..3];\n");
+        assert_eq!(parse("let s = xs[..3];"), "let s = xs[..3];\n");
+        assert_eq!(parse("let s = xs[1..];"), "let s = xs[1..];\n");
+        assert_eq!(parse("let s = xs[..];"), "let s = xs[..];\n");
+    }
+
+    #[test]
+    fn malformed_ranges_are_rejected() {
+        let error = Parser::new().produce_ast("let s = xs[1..=];").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Inclusive range must have an end at line 1, column 16");
+
+        let error = Parser::new().produce_ast("let r = 0..;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected token: SemiColon at line 1, column 12");
+
+        let error = Parser::new().produce_ast("let r = 0..10 step;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected token: SemiColon at line 1, column 19");
+    }
+
+    #[test]
+    fn type_annotations_on_declarations_and_parameters() {
+        assert_eq!(parse("let x: number = 1;"), "let x: number = 1;\n");
+        assert_eq!(parse("let o: {name: string?, tags: string[]} = {};"), "let o: {name: string?, tags: string[]} = {};\n");
+        assert_eq!(parse("func add(a: number, b: number = 1): number { return a; }"), "function add(a: number, b: number = 1): number {\n\treturn a;\n\n}\n");
+        assert_eq!(parse("class P { x: number = 0; }"), "class P {\nx: number = 0;\n}\n");
+    }
+
+    #[test]
+    fn type_aliases() {
+        assert_eq!(parse("type Point = {x: number, y: number};"), "type Point = {x: number, y: number};\n");
+        assert_eq!(parse("type Maybe = string | null;"), "type Maybe = string | null;\n");
+        assert_eq!(parse("type F = (number, string) => bool;"), "type F = (number, string) => bool;\n");
+    }
+
+    #[test]
+    fn malformed_types_are_rejected() {
+        let error = Parser::new().produce_ast("let x: = 1;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected type at line 1, column 8");
+
+        let error = Parser::new().produce_ast("type A number;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected = after type name at line 1, column 8");
+    }
+
+    #[test]
+    fn newline_before_paren_continues_expression() {
+        assert_eq!(parse("let a = b\n(c);"), "let a = b(c);\n");
+    }
+
+    #[test]
+    fn newline_before_bracket_continues_expression() {
+        assert_eq!(parse("let a = b\n[0];"), "let a = b[0];\n");
+    }
+
+    #[test]
+    fn semicolon_separates_array_destructuring() {
+        assert_eq!(parse("a = b;\n[c, d] = e;"), "a = b;\n[c, d] = e;\n");
+    }
+
+    #[test]
+    fn semicolon_separates_parenthesized_expression() {
+        assert_eq!(parse("a = b;\n(c);"), "a = b;\nc;\n");
+    }
+
+    #[test]
+    fn return_value_on_next_line() {
+        assert_eq!(parse("func f() {\nreturn\n1;\n}"), "function f() {\n\treturn 1;\n\n}\n");
+    }
+
+    #[test]
+    fn for_init_consumes_terminator() {
+        assert_eq!(parse("for let i = 0; i < 3; i += 1 { f(i); }"), "for (let i = 0; i < 3; i += 1) {\nf(i);\n}\n");
+    }
+
+    #[test]
+    fn block_statements_need_no_terminator() {
+        assert_eq!(parse("if a { b(); } c();"), "if (a) {\nb();\n}\nc();\n");
+    }
+
+    #[test]
+    fn stray_semicolons_are_empty_statements() {
+        assert_eq!(parse(";;"), ";\n;\n");
+    }
+
+    #[test]
+    fn parse_expression_str_parses_single_expression() {
+        let expression = Parser::new().parse_expression_str("price * qty > 100").unwrap();
+        assert_eq!(expression.to_string(), "price * qty > 100");
+    }
+
+    #[test]
+    fn parse_expression_str_rejects_trailing_tokens() {
+        let error = Parser::new().parse_expression_str("price * qty 100").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected trailing input '100' at line 1, column 13");
+    }
+
+    #[test]
+    fn parse_statement_str_rejects_trailing_tokens() {
+        let error = Parser::new().parse_statement_str("let a = 1; let b = 2;").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Unexpected trailing input 'let' at line 1, column 12");
+    }
+
+    #[test]
+    fn standalone_block_is_a_statement() {
+        assert_eq!(parse("let a = 1;\n{\nlet b = a;\n}"), "let a = 1;\n{\nlet b = a;\n}\n");
+    }
+
+    #[test]
+    fn parenthesized_object_literal_is_an_expression_statement() {
+        assert_eq!(parse("({ a: 1 });"), "({a: 1});\n");
+    }
+
+    #[test]
+    fn compound_statements_share_block_parsing() {
+        assert_eq!(parse("if a { b(); } else if c { d(); } else { e(); }"), "if (a) {\nb();\n} else if (c) {\nd();\n} else {\ne();\n}\n");
+        assert_eq!(parse("try { a(); } catch (e) { b(); } finally { c(); }"), "try {\na();\n} catch (e) {\nb();\n} finally {\nc();\n}\n");
+    }
+
+    #[test]
+    fn deep_nesting_fails_with_too_deep() {
+        let source_code = format!("{}x{};", "(".repeat(10_000), ")".repeat(10_000));
+        let error = Parser::new().parse_expression_str(&source_code).err().unwrap();
+        assert_eq!(error.to_string(), "Error: Maximum nesting depth exceeded at line 1, column 65");
+
+        let arrays = format!("let a = {}x{};", "[".repeat(10_000), "]".repeat(10_000));
+        let error = Parser::new().produce_ast(&arrays).err().unwrap();
+        assert_eq!(error.to_string(), "Error: Maximum nesting depth exceeded at line 1, column 73");
+
+        let objects = format!("let o = {}x{};", "{a: ".repeat(10_000), "}".repeat(10_000));
+        let error = Parser::new().produce_ast(&objects).err().unwrap();
+        assert_eq!(error.to_string(), "Error: Maximum nesting depth exceeded at line 1, column 265");
+
+        let functions = format!("let f = {}x{};", "func() { return ".repeat(10_000), "; }".repeat(10_000));
+        let error = Parser::new().produce_ast(&functions).err().unwrap();
+        assert_eq!(error.to_string(), "Error: Maximum nesting depth exceeded at line 1, column 521");
+    }
+
+    #[test]
+    fn deep_prefix_update_fails_with_too_deep() {
+        let source_code = format!("{}x;", "++".repeat(10_000));
+        let error = Parser::new().produce_ast(&source_code).err().unwrap();
+        assert_eq!(error.to_string(), "Error: Maximum nesting depth exceeded at line 1, column 129");
+    }
+
+    #[test]
+    fn flat_chains_do_not_count_toward_depth() {
+        let sum = format!("let s = a{};", " + a".repeat(100_000));
+        assert!(Parser::new().produce_ast(&sum).is_ok());
+
+        let members = format!("let m = x{};", ".a".repeat(100_000));
+        assert!(Parser::new().produce_ast(&members).is_ok());
+
+        let calls = format!("let c = f{};", "(1)".repeat(100_000));
+        assert!(Parser::new().produce_ast(&calls).is_ok());
+
+        let pipeline = format!("let p = x{};", " |> f".repeat(100_000));
+        assert!(Parser::new().produce_ast(&pipeline).is_ok());
+
+        let branches = format!("if a {{ b; }}{} else {{ c; }}", " else if a { b; }".repeat(100_000));
+        assert!(Parser::new().produce_ast(&branches).is_ok());
+
+        let logical = format!("let l = a{};", " or a".repeat(100_000));
+        assert!(Parser::new().produce_ast(&logical).is_ok());
+
+        let assigned = format!("x{} = 1;", ".a".repeat(100_000));
+        assert!(Parser::new().produce_ast(&assigned).is_ok());
+    }
+
+    #[test]
+    fn max_depth_is_configurable() {
+        let source_code = "let a = ((((b))));";
+        assert!(Parser::new().with_max_depth(4).produce_ast(source_code).is_err());
+        assert!(Parser::new().with_max_depth(8).produce_ast(source_code).is_ok());
+    }
+
+    #[test]
+    fn enum_declarations_with_values_and_payloads() {
+        assert_eq!(parse("enum Color { Red, Green, Blue = 10 }"), "enum Color {\nRed,\nGreen,\nBlue = 10,\n}\n");
+        assert_eq!(parse("export enum Shape { Circle(r), Rect(w, h), }"), "export enum Shape {\nCircle(r),\nRect(w, h),\n}\n");
+    }
+
+    #[test]
+    fn match_patterns_use_enum_paths() {
+        assert_eq!(parse("match shape { Shape::Circle(r) => r, Shape::Rect(w, _) => w, Color::Red => 0, _ => 1 }"), "match shape {\nShape::Circle(r) => r,\nShape::Rect(w, _) => w,\nColor::Red => 0,\n_ => 1,\n};\n");
+    }
+
+    #[test]
+    fn path_expressions_resolve_namespaced_names() {
+        assert_eq!(parse("let c = Color::Red;"), "let c = Color::Red;\n");
+        assert_eq!(parse("let s = Shape::Rect(1, 2).area + utils::math::PI;"), "let s = Shape::Rect(1, 2).area + utils::math::PI;\n");
+    }
+
+    #[test]
+    fn path_expression_requires_name_after_separator() {
+        let error = Parser::new().parse_expression_str("Color::1").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Expected name after :: at line 1, column 8");
+    }
+
+    #[test]
+    fn pipeline_chains_calls_left_to_right() {
+        assert_eq!(parse("let r = xs |> filter(f) |> sort() |> format;"), "let r = xs |> filter(f) |> sort() |> format();\n");
+        assert_eq!(parse("let r = a + b |> f(c) == d ?? e;"), "let r = a + b |> f(c) == d ?? e;\n");
+    }
+
+    #[test]
+    fn pipeline_desugars_into_call() {
+        let pipeline = ast::PipelineExpression::new(
+            Box::new(ast::Identifier::new(String::from("xs"))),
+            Box::new(ast::Identifier::new(String::from("filter"))),
+            vec![Box::new(ast::Identifier::new(String::from("f")))],
+        );
+        assert_eq!(pipeline.desugar().to_string(), "filter(xs, f)");
+    }
+
+    #[test]
+    fn generator_functions_yield_values() {
+        assert_eq!(parse("func* gen() { yield 1; yield* other(); yield; }"), "function* gen() {\n\tyield 1;\nyield* other();\nyield;\n\n}\n");
+        assert_eq!(parse("let g = func*() { let x = yield 1; };"), "let g = function*() {\nlet x = yield 1;\n};\n");
+        assert_eq!(parse("foreach x in gen() { f(x); }"), "for (x in gen()) {\nf(x);\n}\n");
+    }
+
+    #[test]
+    fn yield_outside_generator_is_rejected() {
+        let error = Parser::new().produce_ast("func f() { yield 1; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Yield expression must be inside a generator function at line 1, column 12");
+
+        let error = Parser::new().produce_ast("func* g() { let f = () => { yield 1; }; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Yield expression must be inside a generator function at line 1, column 29");
+
+        let error = Parser::new().produce_ast("func* g() { let f = () => yield 1; }").err().unwrap();
+        assert_eq!(error.to_string(), "Error: Yield expression must be inside a generator function at line 1, column 27");
+
+        assert!(Parser::new().produce_ast("func* g() { let f = () => 1; yield f(); }").is_ok());
+    }
+
+    fn expected(source_code: &str) -> String {
+        match Parser::new().check_complete(source_code) {
+            Completeness::Complete => return String::from("complete"),
+            Completeness::Incomplete { expected } => return expected,
+            Completeness::Invalid(errors) => return errors[0].to_string(),
+        }
+    }
+
+    #[test]
+    fn check_complete_accepts_finished_input() {
+        assert_eq!(expected("func f() { return 1; }"), "complete");
+    }
+
+    #[test]
+    fn check_complete_detects_open_delimiters() {
+        assert_eq!(expected("func f() {"), "}");
+        assert_eq!(expected("loop {\nbreak;"), "}");
+        assert_eq!(expected("f(1, "), ")");
+        assert_eq!(expected("let a = [1, 2"), "]");
+        assert_eq!(expected("let a = 1 +"), "Expected expression");
+        assert_eq!(expected("let x:"), "Expected type");
+        assert_eq!(expected("if a >"), "Expected expression");
+        assert_eq!(expected("a <"), "Expected expression");
+        assert_eq!(expected("let f = (a, b"), ")");
+        assert_eq!(expected("let f = (a,"), ")");
+        assert_eq!(expected("let f = (...rest"), ")");
+        assert_eq!(expected("let x = (a + b"), ")");
+    }
+
+    #[test]
+    fn check_complete_detects_open_string_and_comment() {
+        assert_eq!(expected("let a = \"abc"), "\"");
+        assert_eq!(expected("/* note"), "*/");
+        assert_eq!(expected("/* a ("), "*/");
+        assert_eq!(expected("loop {\n/* } */"), "}");
+    }
+
+    #[test]
+    fn reused_parser_resets_context_after_errors() {
+        let mut parser = Parser::new();
+
+        assert!(matches!(parser.check_complete("func f() {"), Completeness::Incomplete { .. }));
+        assert!(matches!(parser.check_complete("return 1;"), Completeness::Invalid(_)));
+
+        assert!(matches!(parser.check_complete("'a: loop {"), Completeness::Incomplete { .. }));
+        assert!(matches!(parser.check_complete("loop { 'a: loop { break 'a; } }"), Completeness::Complete));
+
+        assert!(parser.produce_ast("loop { break").is_err());
+        assert!(parser.produce_ast("break;").is_err());
+    }
+
+    #[test]
+    fn check_complete_reports_invalid_input() {
+        assert_eq!(expected("let a = );"), "Error: Unexpected token: CloseParen at line 1, column 9");
+        assert_eq!(expected("const x;"), "Error: Constant variable 'x' must be initialized at line 1, column 9");
+        assert_eq!(expected("let [a];"), "Error: Destructuring declaration '[a]' must be initialized at line 1, column 9");
+        assert_eq!(expected("let x = 1; const y;"), "Error: Constant variable 'y' must be initialized at line 1, column 20");
+        assert_eq!(expected("x = 1 ++"), "Error: Invalid target for ++ operator at line 1, column 9");
     }
 }
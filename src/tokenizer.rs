@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use crate::error::ParseError;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum TokenType {
@@ -6,11 +7,12 @@ pub enum TokenType {
     String,
     Identifier,
     Equals,
+    CompoundAssignment,
+    Arrow,
     OpenParen,
     CloseParen,
     BinaryOperator,
     UnaryOperator,
-    LogicalOperator,
     ComparisonOperator,
     Whitespace,
     SemiColon,
@@ -20,7 +22,14 @@ pub enum TokenType {
     Comma,
     ColonColon,
     Colon,
+    Question,
+    QuestionDot,
+    NullishCoalescing,
+    Pipeline,
     Dot,
+    Ellipsis,
+    DotDot,
+    DotDotEquals,
     LSquirly,
     RSquirly,
     OpenBracket,
@@ -38,12 +47,26 @@ pub enum TokenType {
     For,
     In,
     Break,
+    Continue,
+    Label,
     Not,
     And,
     Or,
     Xor,
+    Match,
+    Try,
+    Catch,
+    Finally,
+    Throw,
+    Yield,
     Import,
+    Export,
     Class,
+    Enum,
+    Extends,
+    Static,
+    This,
+    Super,
 
     EndOfFile,
 }
@@ -52,6 +75,8 @@ pub enum TokenType {
 pub struct Token {
     pub r#type: TokenType,
     pub value: String,
+    pub line: usize,
+    pub column: usize,
 }
 
 lazy_static::lazy_static! {
@@ -69,11 +94,24 @@ lazy_static::lazy_static! {
         map.insert("for", TokenType::For);
         map.insert("in", TokenType::In);
         map.insert("break", TokenType::Break);
-        map.insert("not", TokenType::LogicalOperator);
-        map.insert("and", TokenType::LogicalOperator);
-        map.insert("or", TokenType::LogicalOperator);
+        map.insert("continue", TokenType::Continue);
+        map.insert("not", TokenType::Not);
+        map.insert("and", TokenType::And);
+        map.insert("or", TokenType::Or);
+        map.insert("match", TokenType::Match);
+        map.insert("try", TokenType::Try);
+        map.insert("catch", TokenType::Catch);
+        map.insert("finally", TokenType::Finally);
+        map.insert("throw", TokenType::Throw);
+        map.insert("yield", TokenType::Yield);
         map.insert("import", TokenType::Import);
+        map.insert("export", TokenType::Export);
         map.insert("class", TokenType::Class);
+        map.insert("enum", TokenType::Enum);
+        map.insert("extends", TokenType::Extends);
+        map.insert("static", TokenType::Static);
+        map.insert("this", TokenType::This);
+        map.insert("super", TokenType::Super);
         return map;
     };
 }
@@ -82,6 +120,8 @@ pub fn create_token(value: &str, token_type: TokenType) -> Token {
     return Token {
         r#type: token_type,
         value: value.to_string(),
+        line: 0,
+        column: 0,
     };
 }
 
@@ -97,137 +137,222 @@ pub fn is_float(src: &str) -> bool {
     return src.parse::<f64>().is_ok()
 }
 
+pub fn starts_with(src: &[char], pattern: &str) -> bool {
+    return src.iter().take(pattern.len()).copied().eq(pattern.chars());
+}
+
 pub fn is_whitespace(src: &str) -> bool {
     return src == " " || src == "\t" || src == "\n" || src == "\r"
 }
 
-pub fn tokenize(source_code: &str) -> Vec<Token> {
+pub fn tokenize(source_code: &str) -> Result<Vec<Token>, ParseError> {
     let mut tokens = Vec::new();
-    let mut src: Vec<char> = source_code.chars().collect();
+    let source: Vec<char> = source_code.chars().collect();
+    let mut src: &[char] = &source;
+
+    let mut line = 1;
+    let mut column = 1;
+    let mut consumed = 0;
+    let mut located = 0;
 
     while !src.is_empty() {
+        locate_tokens(&mut tokens[located..], line, column);
+        located = tokens.len();
+        advance_location(&source[consumed..source.len() - src.len()], &mut line, &mut column);
+        consumed = source.len() - src.len();
+
         if src[0] == '(' {
             tokens.push(create_token(&src[0].to_string(), TokenType::OpenParen));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == ')' {
             tokens.push(create_token(&src[0].to_string(), TokenType::CloseParen));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == '{' {
             tokens.push(create_token(&src[0].to_string(), TokenType::LSquirly));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == '}' {
             tokens.push(create_token(&src[0].to_string(), TokenType::RSquirly));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == '[' {
             tokens.push(create_token(&src[0].to_string(), TokenType::OpenBracket));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == ']' {
             tokens.push(create_token(&src[0].to_string(), TokenType::CloseBracket));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if "+-*/%&|^".contains(src[0]) {
+            if starts_with(src, "**=") || starts_with(src, "//=") {
+                tokens.push(create_token(&src[..3].iter().collect::<String>(), TokenType::CompoundAssignment));
+                src = &src[3..];
+                continue;
+            }
+            if src.len() > 1 && src[1] == '=' {
+                tokens.push(create_token(&src[..2].iter().collect::<String>(), TokenType::CompoundAssignment));
+                src = &src[2..];
+                continue;
+            }
+            if starts_with(src, "|>") {
+                tokens.push(create_token("|>", TokenType::Pipeline));
+                src = &src[2..];
+                continue;
+            }
+            if starts_with(src, "--") {
+                tokens.push(create_token("--", TokenType::UnaryOperator));
+                src = &src[2..];
+                continue;
+            }
             if (src[0] == '-' && is_int(&src[1..].iter().collect::<String>()))
                 || (src[0] == '-' && is_float(&src[1..].iter().collect::<String>()))
                 || (src[0] == '-' && is_alpha(&src[1..].iter().collect::<String>()))
             {
                 tokens.push(create_token(&src[0].to_string(), TokenType::UnaryOperator));
-                src = src[1..].to_vec();
+                src = &src[1..];
                 continue;
             }
-            if src[0] == '+' && src[1] == '+' {
+            if starts_with(src, "++") {
                 tokens.push(create_token("++", TokenType::UnaryOperator));
-                src = src[2..].to_vec();
-                continue;
-            }
-            if src[0] == '-' && src[1] == '-' {
-                tokens.push(create_token("--", TokenType::UnaryOperator));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
-            if src[0] == '*' && src[1] == '*' {
+            if starts_with(src, "**") {
                 tokens.push(create_token("**", TokenType::BinaryOperator));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
-            if src[0] == '/' && src[1] == '*' {
+            if starts_with(src, "/*") {
                 tokens.push(create_token("/*", TokenType::OpenComment));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
-            if src[0] == '*' && src[1] == '/' {
+            if starts_with(src, "*/") {
                 tokens.push(create_token("*/", TokenType::CloseComment));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
-            if src[0] == '/' && src[1] == '/' {
+            if starts_with(src, "//") {
                 tokens.push(create_token("//", TokenType::BinaryOperator));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
             tokens.push(create_token(&src[0].to_string(), TokenType::BinaryOperator));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == '=' {
+            if src.len() > 1 && src[1] == '>' {
+                tokens.push(create_token("=>", TokenType::Arrow));
+                src = &src[2..];
+                continue;
+            }
             tokens.push(create_token(&src[0].to_string(), TokenType::Equals));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == '>' {
-            if src[1] == '>' {
+            if starts_with(src, ">>=") {
+                tokens.push(create_token(">>=", TokenType::CompoundAssignment));
+                src = &src[3..];
+                continue;
+            }
+            if src.len() > 1 && src[1] == '>' {
                 tokens.push(create_token(">>", TokenType::BinaryOperator));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
             tokens.push(create_token(&src[0].to_string(), TokenType::ComparisonOperator));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == '<' {
-            if src[1] == '<' {
+            if starts_with(src, "<<=") {
+                tokens.push(create_token("<<=", TokenType::CompoundAssignment));
+                src = &src[3..];
+                continue;
+            }
+            if src.len() > 1 && src[1] == '<' {
                 tokens.push(create_token("<<", TokenType::BinaryOperator));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
             tokens.push(create_token(&src[0].to_string(), TokenType::ComparisonOperator));
-            src = src[1..].to_vec();
-        } else if src[0] == '>' && src[1] == '=' {
+            src = &src[1..];
+        } else if starts_with(src, ">=") {
             tokens.push(create_token(">=", TokenType::ComparisonOperator));
-            src = src[2..].to_vec();
-        } else if src[0] == '<' && src[1] == '=' {
+            src = &src[2..];
+        } else if starts_with(src, "<=") {
             tokens.push(create_token("<=", TokenType::ComparisonOperator));
-            src = src[2..].to_vec();
-        } else if src[0] == '=' && src[1] == '=' {
+            src = &src[2..];
+        } else if starts_with(src, "==") {
             tokens.push(create_token("==", TokenType::ComparisonOperator));
-            src = src[2..].to_vec();
-        } else if src[0] == '!' && src[1] == '=' {
+            src = &src[2..];
+        } else if starts_with(src, "!=") {
             tokens.push(create_token("!=", TokenType::ComparisonOperator));
-            src = src[2..].to_vec();
+            src = &src[2..];
+        } else if src[0] == '?' {
+            if src.len() > 1 && src[1] == '?' {
+                tokens.push(create_token("??", TokenType::NullishCoalescing));
+                src = &src[2..];
+                continue;
+            }
+            if src.len() > 1 && src[1] == '.' && !(src.len() > 2 && src[2].is_ascii_digit()) {
+                tokens.push(create_token("?.", TokenType::QuestionDot));
+                src = &src[2..];
+                continue;
+            }
+            tokens.push(create_token(&src[0].to_string(), TokenType::Question));
+            src = &src[1..];
         } else if src[0] == ';' {
             tokens.push(create_token(&src[0].to_string(), TokenType::SemiColon));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == ',' {
             tokens.push(create_token(&src[0].to_string(), TokenType::Comma));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == '.' {
+            if src.len() > 2 && src[1] == '.' && src[2] == '.' {
+                tokens.push(create_token("...", TokenType::Ellipsis));
+                src = &src[3..];
+                continue;
+            }
+            if starts_with(src, "..=") {
+                tokens.push(create_token("..=", TokenType::DotDotEquals));
+                src = &src[3..];
+                continue;
+            }
+            if starts_with(src, "..") {
+                tokens.push(create_token("..", TokenType::DotDot));
+                src = &src[2..];
+                continue;
+            }
             tokens.push(create_token(&src[0].to_string(), TokenType::Dot));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else if src[0] == ':' {
-            if src[1] == ':' {
+            if src.len() > 1 && src[1] == ':' {
                 tokens.push(create_token("::", TokenType::ColonColon));
-                src = src[2..].to_vec();
+                src = &src[2..];
                 continue;
             }
             tokens.push(create_token(&src[0].to_string(), TokenType::Colon));
-            src = src[1..].to_vec();
+            src = &src[1..];
+        } else if src[0] == '\'' {
+            src = &src[1..];
+            let mut label = String::new();
+            while !src.is_empty() && is_alpha(&src[0].to_string()) {
+                label.push(src[0]);
+                src = &src[1..];
+            }
+
+            if label.is_empty() {
+                return Err(ParseError::Syntax { message: String::from("Expected label name after '"), line, column });
+            }
+
+            tokens.push(create_token(&label, TokenType::Label));
         } else if src[0] == '\"' {
-            src = src[1..].to_vec();
+            src = &src[1..];
             let mut string_value = String::new();
             while !src.is_empty() && src[0] != '\"' {
                 string_value.push(src[0]);
-                src = src[1..].to_vec();
+                src = &src[1..];
             }
 
             if src.is_empty() {
-                println!("Error: Unterminated string");
-                std::process::exit(0);
+                return Err(ParseError::UnexpectedEof { message: String::from("Unterminated string"), line, column });
             }
 
             tokens.push(create_token(&string_value, TokenType::String));
-            src = src[1..].to_vec();
+            src = &src[1..];
         } else {
             if is_int(&src[0].to_string()) || (src[0] == '-' && is_int(&src[1..].iter().collect::<String>())) {
                 let mut num = String::new();
@@ -243,12 +368,12 @@ pub fn tokenize(source_code: &str) -> Vec<Token> {
                         is_float_num = true;
                     }
                     num.push(src[0]);
-                    src = src[1..].to_vec();
+                    src = &src[1..];
                 }
 
                 while !src.is_empty() && is_int(&src[0].to_string()) {
                     num.push(src[0]);
-                    src = src[1..].to_vec();
+                    src = &src[1..];
                 }
 
                 tokens.push(create_token(&num, TokenType::Number));
@@ -256,7 +381,7 @@ pub fn tokenize(source_code: &str) -> Vec<Token> {
                 let mut identifier = String::new();
                 while !src.is_empty() && is_alpha(&src[0].to_string()) {
                     identifier.push(src[0]);
-                    src = src[1..].to_vec();
+                    src = &src[1..];
                 }
 
                 if let Some(&reserved) = KEYWORDS.get(&identifier as &str) {
@@ -265,15 +390,37 @@ pub fn tokenize(source_code: &str) -> Vec<Token> {
                     tokens.push(create_token(&identifier, TokenType::Identifier));
                 }
             } else if is_whitespace(&src[0].to_string()) {
-                src = src[1..].to_vec();
+                src = &src[1..];
             } else {
-                println!("Error: Invalid character '{}'", src[0]);
-                std::process::exit(0);
+                return Err(ParseError::Syntax { message: format!("Invalid character '{}'", src[0]), line, column });
             }
         }
     }
 
+    locate_tokens(&mut tokens[located..], line, column);
+    located = tokens.len();
+    advance_location(&source[consumed..], &mut line, &mut column);
+
     tokens.push(create_token("EndOfFile", TokenType::EndOfFile));
-    return tokens;
+    locate_tokens(&mut tokens[located..], line, column);
+    return Ok(tokens);
+}
+
+fn locate_tokens(tokens: &mut [Token], line: usize, column: usize) {
+    for token in tokens {
+        token.line = line;
+        token.column = column;
+    }
+}
+
+fn advance_location(consumed: &[char], line: &mut usize, column: &mut usize) {
+    for c in consumed {
+        if *c == '\n' {
+            *line += 1;
+            *column = 1;
+        } else {
+            *column += 1;
+        }
+    }
 }
 